@@ -1,7 +1,13 @@
 //! Nuclear Kernel Library Core module.
 
+mod decay;
+pub use decay::{activity, decay_constant, DecayMode, DecayType};
+
 mod element;
-pub use element::Element;
+pub use element::{
+    Element, ElementCategory, ElementIter, ParseElementError, PeriodicNeighbors,
+    TryFromAtomicNumberError,
+};
 
 mod zai;
-pub use zai::Zai;
+pub use zai::{Zai, ZaiError};
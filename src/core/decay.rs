@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use crate::core::Zai;
+use crate::lazy::Lazy;
+
+static DECAY_MODES: Lazy<HashMap<Zai, Vec<DecayMode>>> = Lazy::new(|| {
+    let source = include_str!("../../data/decay/decay_modes");
+    let mut decay_modes: HashMap<Zai, Vec<DecayMode>> = HashMap::new();
+    for line in source.lines() {
+        let (zai, mode) = parse_decay_mode(line).expect("invalid embedded decay modes data");
+        decay_modes.entry(zai).or_default().push(mode);
+    }
+    decay_modes
+});
+
+static HALF_LIVES: Lazy<HashMap<Zai, f64>> = Lazy::new(|| {
+    include_str!("../../data/decay/half_lives")
+        .lines()
+        .map(|line| parse_half_life(line).expect("invalid embedded half-lives data"))
+        .collect()
+});
+
+/// Radioactive decay type.
+///
+/// # References
+///
+/// [Wikipedia: Radioactive decay](https://en.wikipedia.org/wiki/Radioactive_decay)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum DecayType {
+    /// α decay.
+    Alpha,
+    /// β⁻ decay.
+    BetaMinus,
+    /// β⁺ decay and/or electron capture.
+    BetaPlus,
+    /// Isomeric transition.
+    IsomericTransition,
+    /// Spontaneous fission.
+    SpontaneousFission,
+}
+
+/// Radioactive decay mode: decay type and its branching ratio.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::{DecayType, Zai};
+///
+/// let co60 = Zai::new(27, 60, 0);
+/// let modes = co60.decay_modes().unwrap();
+/// assert_eq!(modes[0].decay_type(), DecayType::BetaMinus);
+/// assert_eq!(modes[0].branching_ratio(), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecayMode {
+    decay_type: DecayType,
+    branching_ratio: f64,
+}
+
+impl DecayMode {
+    /// Returns decay mode's type.
+    pub fn decay_type(&self) -> DecayType {
+        self.decay_type
+    }
+
+    /// Returns decay mode's branching ratio (in `[0, 1]`).
+    pub fn branching_ratio(&self) -> f64 {
+        self.branching_ratio
+    }
+}
+
+// Returns embedded decay modes of `zai`.
+pub(crate) fn decay_modes(zai: Zai) -> Option<Vec<DecayMode>> {
+    DECAY_MODES.get().get(&zai).cloned()
+}
+
+/// Returns nuclide's decay constant in s⁻¹.
+///
+/// # Format
+///
+/// Decay constant is derived from nuclide's [half-life](Zai::half_life):
+///
+/// ```text
+/// λ = ln(2) / T½
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::{decay_constant, Zai};
+///
+/// let co60 = decay_constant(Zai::new(27, 60, 0)).unwrap();
+/// assert!((co60 / 4.167e-9 - 1.0).abs() < 1e-3);
+/// assert_eq!(decay_constant(Zai::new(26, 56, 0)), None);
+/// ```
+///
+/// # Returns
+///
+/// - `Some(lambda)` if nuclide's half-life is available
+/// - `None` for stable nuclides and nuclides without available decay data
+pub fn decay_constant(zai: Zai) -> Option<f64> {
+    half_life(zai).map(|half_life| std::f64::consts::LN_2 / half_life)
+}
+
+/// Returns activity in becquerels (Bq) of a given number of atoms of nuclide.
+///
+/// # Format
+///
+/// Activity is derived from nuclide's [decay constant](decay_constant) λ:
+///
+/// ```text
+/// A = λ × N
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::{activity, Zai};
+///
+/// let cs137 = activity(Zai::new(55, 137, 0), 1e20).unwrap();
+/// assert!((cs137 / 7.30e10 - 1.0).abs() < 1e-2);
+/// assert_eq!(activity(Zai::new(26, 56, 0), 1e20), None);
+/// ```
+///
+/// # Returns
+///
+/// - `Some(activity)` if nuclide's half-life is available
+/// - `None` for stable nuclides and nuclides without available decay data
+pub fn activity(zai: Zai, atoms: f64) -> Option<f64> {
+    decay_constant(zai).map(|lambda| lambda * atoms)
+}
+
+// Returns embedded half-life (in seconds) of `zai`.
+pub(crate) fn half_life(zai: Zai) -> Option<f64> {
+    HALF_LIVES.get().get(&zai).copied()
+}
+
+// Parse a half-lives data record.
+fn parse_half_life(line: &str) -> Option<(Zai, f64)> {
+    let mut fields = line.split_whitespace();
+    let zai = Zai::from_name(fields.nth(3)?)?;
+    let half_life = fields.next()?.parse().ok()?;
+    Some((zai, half_life))
+}
+
+// Parse a decay modes data record.
+fn parse_decay_mode(line: &str) -> Option<(Zai, DecayMode)> {
+    let mut fields = line.split_whitespace();
+    let zai = Zai::from_name(fields.nth(3)?)?;
+    let decay_type = match fields.next()? {
+        "A" => DecayType::Alpha,
+        "B-" => DecayType::BetaMinus,
+        "B+" => DecayType::BetaPlus,
+        "IT" => DecayType::IsomericTransition,
+        "SF" => DecayType::SpontaneousFission,
+        _ => return None,
+    };
+    let branching_ratio = fields.next()?.parse().ok()?;
+    let mode = DecayMode {
+        decay_type,
+        branching_ratio,
+    };
+    Some((zai, mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn branching_ratios() {
+        for (zai, modes) in DECAY_MODES.get() {
+            let total: f64 = modes.iter().map(DecayMode::branching_ratio).sum();
+            assert!((total - 1.0).abs() < 1e-9, "{}", zai.name());
+            assert!(!zai.is_stable(), "{}", zai.name());
+        }
+    }
+
+    #[test]
+    fn half_lives() {
+        for (zai, half_life) in HALF_LIVES.get() {
+            assert!(*half_life > 0.0, "{}", zai.name());
+            assert!(DECAY_MODES.get().contains_key(zai), "{}", zai.name());
+        }
+        assert_eq!(HALF_LIVES.get().len(), DECAY_MODES.get().len());
+    }
+
+    #[test]
+    fn decay_constant_co60() {
+        let lambda = decay_constant(Zai::new(27, 60, 0)).unwrap();
+        assert!((lambda - 4.1670e-9).abs() < 1e-12);
+        assert_eq!(decay_constant(Zai::new(26, 56, 0)), None);
+    }
+
+    #[test]
+    fn activity_linear() {
+        let co60 = Zai::new(27, 60, 0);
+        let activity_1 = activity(co60, 1e18).unwrap();
+        let activity_2 = activity(co60, 2e18).unwrap();
+        assert!((activity_2 - 2.0 * activity_1).abs() <= 1e-12 * activity_2);
+        assert_eq!(activity(co60, 0.0), Some(0.0));
+        assert_eq!(activity(Zai::new(26, 56, 0), 1e18), None);
+    }
+
+    #[test]
+    fn parse() {
+        let (zai, mode) = parse_decay_mode(" 98 252 0 Cf252   SF  0.03092").unwrap();
+        assert_eq!(zai, Zai::new(98, 252, 0));
+        assert_eq!(mode.decay_type(), DecayType::SpontaneousFission);
+        assert_eq!(mode.branching_ratio(), 0.03092);
+        assert!(parse_decay_mode(" 98 252 0 Cf252   XX  1").is_none());
+        assert!(parse_decay_mode(" 98 252 0 Cf252   SF").is_none());
+    }
+}
@@ -1,3 +1,28 @@
+use std::{cmp::Ordering, error::Error, fmt::Display, iter::FusedIterator, str::FromStr};
+
+use crate::core::Zai;
+use crate::lazy::Lazy;
+
+// Isotopic abundances `(A, fraction)` indexed by atomic number.
+static ISOTOPIC_ABUNDANCES: Lazy<Vec<Vec<(u32, f64)>>> = Lazy::new(|| {
+    let source = include_str!("../../data/abundances/abundances");
+    let mut abundances = vec![Vec::new(); 119];
+    for line in source.lines() {
+        let (z, a, fraction) =
+            parse_abundance(line).expect("invalid embedded isotopic abundances data");
+        abundances[z].push((a, fraction));
+    }
+    abundances
+});
+
+// Parse an isotopic abundance record into `(Z, A, fraction)`.
+fn parse_abundance(line: &str) -> Option<(usize, u32, f64)> {
+    let z = line.get(..3)?.trim().parse().ok()?;
+    let a = line.get(4..7)?.trim().parse().ok()?;
+    let fraction = line.get(35..)?.trim().parse().ok()?;
+    Some((z, a, fraction))
+}
+
 /// Periodic table chemical element.
 ///
 /// # Examples
@@ -22,6 +47,7 @@
 ///
 /// - Chemical element from *Hydrogen* (Z = 1) to *Oganesson* (Z = 118) are included.
 /// - `Element` enum is marked as non exhaustive for future-proofing.
+/// - With the `serde` feature, `Element` is (de)serialized as its symbol.
 ///
 /// # References
 ///
@@ -275,11 +301,43 @@ pub enum Element {
 }
 
 impl Element {
-    /// Maximum atomic number.
-    pub(crate) const MAX_ATOMIC_NUMBER: u32 = 118;
+    /// Minimum atomic number (*Hydrogen*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let hydrogen = Element::from_atomic_number(Element::MIN_ATOMIC_NUMBER);
+    /// assert_eq!(hydrogen, Some(Element::Hydrogen));
+    /// ```
+    pub const MIN_ATOMIC_NUMBER: u32 = 1;
+
+    /// Maximum atomic number (*Oganesson*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let oganesson = Element::from_atomic_number(Element::MAX_ATOMIC_NUMBER);
+    /// assert_eq!(oganesson, Some(Element::Oganesson));
+    /// ```
+    pub const MAX_ATOMIC_NUMBER: u32 = 118;
+
+    /// Number of elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::iter().count(), Element::COUNT);
+    /// ```
+    pub const COUNT: usize = 118;
 
     /// Elements array for iterator.
-    const ELEMENTS: [Self; 118] = [
+    const ELEMENTS: [Self; Self::COUNT] = [
         Self::Hydrogen,
         Self::Helium,
         Self::Lithium,
@@ -413,7 +471,16 @@ impl Element {
     /// use nkl::core::Element;
     ///
     /// assert_eq!(Element::from_name("Hydrogen"), Some(Element::Hydrogen));
+    /// assert_eq!(Element::from_name("Aluminum"), Some(Element::Aluminium));
     /// ```
+    ///
+    /// # Notes
+    ///
+    /// Following alternate spellings are accepted along IUPAC names (returned
+    /// by [`name`](Self::name)):
+    /// - *Aluminum* for *Aluminium*
+    /// - *Cesium* for *Caesium*
+    /// - *Sulphur* for *Sulfur*
     pub fn from_name(name: &str) -> Option<Self> {
         match name.to_ascii_lowercase().as_str() {
             "hydrogen" => Some(Self::Hydrogen),
@@ -428,10 +495,10 @@ impl Element {
             "neon" => Some(Self::Neon),
             "sodium" => Some(Self::Sodium),
             "magnesium" => Some(Self::Magnesium),
-            "aluminium" => Some(Self::Aluminium),
+            "aluminium" | "aluminum" => Some(Self::Aluminium),
             "silicon" => Some(Self::Silicon),
             "phosphorus" => Some(Self::Phosphorus),
-            "sulfur" => Some(Self::Sulfur),
+            "sulfur" | "sulphur" => Some(Self::Sulfur),
             "chlorine" => Some(Self::Chlorine),
             "argon" => Some(Self::Argon),
             "potassium" => Some(Self::Potassium),
@@ -470,7 +537,7 @@ impl Element {
             "tellurium" => Some(Self::Tellurium),
             "iodine" => Some(Self::Iodine),
             "xenon" => Some(Self::Xenon),
-            "caesium" => Some(Self::Caesium),
+            "caesium" | "cesium" => Some(Self::Caesium),
             "barium" => Some(Self::Barium),
             "lanthanum" => Some(Self::Lanthanum),
             "cerium" => Some(Self::Cerium),
@@ -823,7 +890,7 @@ impl Element {
     ///
     /// assert_eq!(Element::Hydrogen.name(), "Hydrogen");
     /// ```
-    pub fn name(&self) -> &str {
+    pub fn name(&self) -> &'static str {
         match self {
             Self::Hydrogen => "Hydrogen",
             Self::Helium => "Helium",
@@ -955,7 +1022,7 @@ impl Element {
     ///
     /// assert_eq!(Element::Hydrogen.symbol(), "H");
     /// ```
-    pub fn symbol(&self) -> &str {
+    pub fn symbol(&self) -> &'static str {
         match self {
             Self::Hydrogen => "H",
             Self::Helium => "He",
@@ -1398,7 +1465,7 @@ impl Element {
             Element::Gallium => Some(13),
             Element::Germanium => Some(14),
             Element::Arsenic => Some(15),
-            Element::Selenium => Some(61),
+            Element::Selenium => Some(16),
             Element::Bromine => Some(17),
             Element::Krypton => Some(18),
             Element::Rubidium => Some(1),
@@ -1499,7 +1566,7 @@ impl Element {
     /// # References
     ///
     /// [Wikipedia: Block (periodic table)](https://en.wikipedia.org/wiki/Block_(periodic_table))
-    pub fn block(&self) -> &str {
+    pub fn block(&self) -> &'static str {
         match self {
             Element::Hydrogen => "s",
             Element::Helium => "s",
@@ -1622,7 +1689,7 @@ impl Element {
         }
     }
 
-    /// Returns an iterator over all elements.
+    /// Returns an iterator over all elements ordered by atomic number.
     ///
     /// # Examples
     ///
@@ -1632,9 +1699,93 @@ impl Element {
     /// for element in Element::iter() {
     ///     println!("{}", element.name());
     /// }
+    /// assert_eq!(Element::iter().next_back(), Some(Element::Oganesson));
+    /// ```
+    pub fn iter() -> ElementIter {
+        ElementIter(Self::ELEMENTS.iter())
+    }
+
+    /// Returns an iterator over elements of given period (periodic table row).
+    ///
+    /// Elements are yielded by increasing atomic number, f-block elements
+    /// included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let period: Vec<Element> = Element::iter_period(1).collect();
+    /// assert_eq!(period, [Element::Hydrogen, Element::Helium]);
+    /// assert_eq!(Element::iter_period(6).count(), 32);
+    /// ```
+    pub fn iter_period(period: u32) -> impl Iterator<Item = Element> {
+        Self::iter().filter(move |element| element.period() == period)
+    }
+
+    /// Returns an iterator over elements of given group (periodic table column).
+    ///
+    /// Elements are yielded by increasing atomic number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let group: Vec<Element> = Element::iter_group(18).take(2).collect();
+    /// assert_eq!(group, [Element::Helium, Element::Neon]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Lanthanides and actinides (f-block) do not have a group (see
+    /// [`group`](Self::group)) and are never yielded.
+    pub fn iter_group(group: u32) -> impl Iterator<Item = Element> {
+        Self::iter().filter(move |element| element.group() == Some(group))
+    }
+
+    /// Returns all elements ordered by atomic number.
+    ///
+    /// The array is 0-based: the element with atomic number `Z` is at index
+    /// `Z - 1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let elements = Element::all();
+    /// assert_eq!(elements[0], Element::Hydrogen);
+    /// assert_eq!(elements[26 - 1], Element::Iron);
+    /// ```
+    pub fn all() -> &'static [Element; Element::COUNT] {
+        &Self::ELEMENTS
+    }
+
+    /// Returns the lightest element (*Hydrogen*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::lightest(), Element::Hydrogen);
+    /// ```
+    pub fn lightest() -> Element {
+        Self::ELEMENTS[0]
+    }
+
+    /// Returns the heaviest element (*Oganesson*).
+    ///
+    /// # Examples
+    ///
     /// ```
-    pub fn iter() -> impl Iterator<Item = Element> {
-        Self::ELEMENTS.iter().copied()
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::heaviest(), Element::Oganesson);
+    /// ```
+    pub fn heaviest() -> Element {
+        Self::ELEMENTS[Self::COUNT - 1]
     }
 
     /// Returns `true` if this `Element` is an alkali metal.
@@ -1793,4 +1944,1970 @@ impl Element {
                 | Self::Oganesson
         )
     }
+
+    /// Returns `true` if this `Element` has no stable isotope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert!(Element::Technetium.is_radioactive());
+    /// assert!(!Element::Iron.is_radioactive());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Radioactive elements are *Technetium* (Z = 43), *Promethium* (Z = 61) and
+    /// every element from *Bismuth* (Z = 83) onward. Bismuth is included since
+    /// its only primordial isotope Bi209 was shown to decay (T½ ≈ 2.01×10¹⁹ y).
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Stable nuclide](https://en.wikipedia.org/wiki/Stable_nuclide)
+    pub fn is_radioactive(&self) -> bool {
+        matches!(self, Self::Technetium | Self::Promethium) || self.atomic_number() >= 83
+    }
+
+    /// Returns `true` if this `Element` is a metal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert!(Element::Iron.is_metal());
+    /// assert!(!Element::Oxygen.is_metal());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Elements which are neither metalloids nor nonmetals are considered to be
+    /// metals, including superheavy elements whose properties are unknown.
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Metal](https://en.wikipedia.org/wiki/Metal)
+    pub fn is_metal(&self) -> bool {
+        !self.is_metalloid() && !self.is_nonmetal()
+    }
+
+    /// Returns `true` if this `Element` is a nonmetal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert!(Element::Oxygen.is_nonmetal());
+    /// assert!(!Element::Iron.is_nonmetal());
+    /// ```
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Nonmetal](https://en.wikipedia.org/wiki/Nonmetal)
+    pub fn is_nonmetal(&self) -> bool {
+        matches!(
+            self,
+            Self::Hydrogen
+                | Self::Helium
+                | Self::Carbon
+                | Self::Nitrogen
+                | Self::Oxygen
+                | Self::Fluorine
+                | Self::Neon
+                | Self::Phosphorus
+                | Self::Sulfur
+                | Self::Chlorine
+                | Self::Argon
+                | Self::Selenium
+                | Self::Bromine
+                | Self::Krypton
+                | Self::Iodine
+                | Self::Xenon
+                | Self::Radon
+        )
+    }
+
+    /// Returns `true` if this `Element` is a metalloid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert!(Element::Silicon.is_metalloid());
+    /// assert!(!Element::Iron.is_metalloid());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Metalloids are the elements along the metal/nonmetal staircase of the
+    /// periodic table: B, Si, Ge, As, Sb, Te, Po and At.
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Metalloid](https://en.wikipedia.org/wiki/Metalloid)
+    pub fn is_metalloid(&self) -> bool {
+        matches!(
+            self,
+            Self::Boron
+                | Self::Silicon
+                | Self::Germanium
+                | Self::Arsenic
+                | Self::Antimony
+                | Self::Tellurium
+                | Self::Polonium
+                | Self::Astatine
+        )
+    }
+
+    /// Returns `Element`'s single-bond covalent radius in picometers (pm).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Carbon.covalent_radius(), Some(76.));
+    /// assert_eq!(Element::Oganesson.covalent_radius(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(radius)` for elements from *Hydrogen* (Z = 1) to *Curium* (Z = 96)
+    /// - `None` otherwise (no tabulated value)
+    ///
+    /// # Notes
+    ///
+    /// - *Carbon* radius is given for sp3 hybridization.
+    /// - *Manganese*, *Iron* and *Cobalt* radii are given for low spin state.
+    ///
+    /// # References
+    ///
+    /// - Cordero, B., Gómez, V., Platero-Prats, A. E., Revés, M., Echeverría, J.,
+    ///   Cremades, E., Barragán, F. and Alvarez, S.
+    ///   *Covalent radii revisited*.
+    ///   Dalton Transactions, no. 21, 2008, pp. 2832-2838.
+    ///   <https://doi.org/10.1039/B801115J>
+    /// - [Wikipedia: Covalent radius](https://en.wikipedia.org/wiki/Covalent_radius)
+    pub fn covalent_radius(&self) -> Option<f64> {
+        match self {
+            Element::Hydrogen => Some(31.),
+            Element::Helium => Some(28.),
+            Element::Lithium => Some(128.),
+            Element::Beryllium => Some(96.),
+            Element::Boron => Some(84.),
+            Element::Carbon => Some(76.),
+            Element::Nitrogen => Some(71.),
+            Element::Oxygen => Some(66.),
+            Element::Fluorine => Some(57.),
+            Element::Neon => Some(58.),
+            Element::Sodium => Some(166.),
+            Element::Magnesium => Some(141.),
+            Element::Aluminium => Some(121.),
+            Element::Silicon => Some(111.),
+            Element::Phosphorus => Some(107.),
+            Element::Sulfur => Some(105.),
+            Element::Chlorine => Some(102.),
+            Element::Argon => Some(106.),
+            Element::Potassium => Some(203.),
+            Element::Calcium => Some(176.),
+            Element::Scandium => Some(170.),
+            Element::Titanium => Some(160.),
+            Element::Vanadium => Some(153.),
+            Element::Chromium => Some(139.),
+            Element::Manganese => Some(139.),
+            Element::Iron => Some(132.),
+            Element::Cobalt => Some(126.),
+            Element::Nickel => Some(124.),
+            Element::Copper => Some(132.),
+            Element::Zinc => Some(122.),
+            Element::Gallium => Some(122.),
+            Element::Germanium => Some(120.),
+            Element::Arsenic => Some(119.),
+            Element::Selenium => Some(120.),
+            Element::Bromine => Some(120.),
+            Element::Krypton => Some(116.),
+            Element::Rubidium => Some(220.),
+            Element::Strontium => Some(195.),
+            Element::Yttrium => Some(190.),
+            Element::Zirconium => Some(175.),
+            Element::Niobium => Some(164.),
+            Element::Molybdenum => Some(154.),
+            Element::Technetium => Some(147.),
+            Element::Ruthenium => Some(146.),
+            Element::Rhodium => Some(142.),
+            Element::Palladium => Some(139.),
+            Element::Silver => Some(145.),
+            Element::Cadmium => Some(144.),
+            Element::Indium => Some(142.),
+            Element::Tin => Some(139.),
+            Element::Antimony => Some(139.),
+            Element::Tellurium => Some(138.),
+            Element::Iodine => Some(139.),
+            Element::Xenon => Some(140.),
+            Element::Caesium => Some(244.),
+            Element::Barium => Some(215.),
+            Element::Lanthanum => Some(207.),
+            Element::Cerium => Some(204.),
+            Element::Praseodymium => Some(203.),
+            Element::Neodymium => Some(201.),
+            Element::Promethium => Some(199.),
+            Element::Samarium => Some(198.),
+            Element::Europium => Some(198.),
+            Element::Gadolinium => Some(196.),
+            Element::Terbium => Some(194.),
+            Element::Dysprosium => Some(192.),
+            Element::Holmium => Some(192.),
+            Element::Erbium => Some(189.),
+            Element::Thulium => Some(190.),
+            Element::Ytterbium => Some(187.),
+            Element::Lutetium => Some(187.),
+            Element::Hafnium => Some(175.),
+            Element::Tantalum => Some(170.),
+            Element::Tungsten => Some(162.),
+            Element::Rhenium => Some(151.),
+            Element::Osmium => Some(144.),
+            Element::Iridium => Some(141.),
+            Element::Platinum => Some(136.),
+            Element::Gold => Some(136.),
+            Element::Mercury => Some(132.),
+            Element::Thallium => Some(145.),
+            Element::Lead => Some(146.),
+            Element::Bismuth => Some(148.),
+            Element::Polonium => Some(140.),
+            Element::Astatine => Some(150.),
+            Element::Radon => Some(150.),
+            Element::Francium => Some(260.),
+            Element::Radium => Some(221.),
+            Element::Actinium => Some(215.),
+            Element::Thorium => Some(206.),
+            Element::Protactinium => Some(200.),
+            Element::Uranium => Some(196.),
+            Element::Neptunium => Some(190.),
+            Element::Plutonium => Some(187.),
+            Element::Americium => Some(180.),
+            Element::Curium => Some(169.),
+            Element::Berkelium => None,
+            Element::Californium => None,
+            Element::Einsteinium => None,
+            Element::Fermium => None,
+            Element::Mendelevium => None,
+            Element::Nobelium => None,
+            Element::Lawrencium => None,
+            Element::Rutherfordium => None,
+            Element::Dubnium => None,
+            Element::Seaborgium => None,
+            Element::Bohrium => None,
+            Element::Hassium => None,
+            Element::Meitnerium => None,
+            Element::Darmstadtium => None,
+            Element::Roentgenium => None,
+            Element::Copernicium => None,
+            Element::Nihonium => None,
+            Element::Flerovium => None,
+            Element::Moscovium => None,
+            Element::Livermorium => None,
+            Element::Tennessine => None,
+            Element::Oganesson => None,
+        }
+    }
+
+    /// Returns `Element`'s ground-state electron configuration.
+    ///
+    /// Configuration is given in noble gas shorthand notation, with subshells
+    /// sorted by principal quantum number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Hydrogen.electron_configuration(), "1s1");
+    /// assert_eq!(Element::Iron.electron_configuration(), "[Ar] 3d6 4s2");
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Configurations of elements from *Meitnerium* (Z = 109) onward are predicted.
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Electron configurations of the elements (data page)](https://en.wikipedia.org/wiki/Electron_configurations_of_the_elements_(data_page))
+    pub fn electron_configuration(&self) -> &'static str {
+        match self {
+            Element::Hydrogen => "1s1",
+            Element::Helium => "1s2",
+            Element::Lithium => "[He] 2s1",
+            Element::Beryllium => "[He] 2s2",
+            Element::Boron => "[He] 2s2 2p1",
+            Element::Carbon => "[He] 2s2 2p2",
+            Element::Nitrogen => "[He] 2s2 2p3",
+            Element::Oxygen => "[He] 2s2 2p4",
+            Element::Fluorine => "[He] 2s2 2p5",
+            Element::Neon => "[He] 2s2 2p6",
+            Element::Sodium => "[Ne] 3s1",
+            Element::Magnesium => "[Ne] 3s2",
+            Element::Aluminium => "[Ne] 3s2 3p1",
+            Element::Silicon => "[Ne] 3s2 3p2",
+            Element::Phosphorus => "[Ne] 3s2 3p3",
+            Element::Sulfur => "[Ne] 3s2 3p4",
+            Element::Chlorine => "[Ne] 3s2 3p5",
+            Element::Argon => "[Ne] 3s2 3p6",
+            Element::Potassium => "[Ar] 4s1",
+            Element::Calcium => "[Ar] 4s2",
+            Element::Scandium => "[Ar] 3d1 4s2",
+            Element::Titanium => "[Ar] 3d2 4s2",
+            Element::Vanadium => "[Ar] 3d3 4s2",
+            Element::Chromium => "[Ar] 3d5 4s1",
+            Element::Manganese => "[Ar] 3d5 4s2",
+            Element::Iron => "[Ar] 3d6 4s2",
+            Element::Cobalt => "[Ar] 3d7 4s2",
+            Element::Nickel => "[Ar] 3d8 4s2",
+            Element::Copper => "[Ar] 3d10 4s1",
+            Element::Zinc => "[Ar] 3d10 4s2",
+            Element::Gallium => "[Ar] 3d10 4s2 4p1",
+            Element::Germanium => "[Ar] 3d10 4s2 4p2",
+            Element::Arsenic => "[Ar] 3d10 4s2 4p3",
+            Element::Selenium => "[Ar] 3d10 4s2 4p4",
+            Element::Bromine => "[Ar] 3d10 4s2 4p5",
+            Element::Krypton => "[Ar] 3d10 4s2 4p6",
+            Element::Rubidium => "[Kr] 5s1",
+            Element::Strontium => "[Kr] 5s2",
+            Element::Yttrium => "[Kr] 4d1 5s2",
+            Element::Zirconium => "[Kr] 4d2 5s2",
+            Element::Niobium => "[Kr] 4d4 5s1",
+            Element::Molybdenum => "[Kr] 4d5 5s1",
+            Element::Technetium => "[Kr] 4d5 5s2",
+            Element::Ruthenium => "[Kr] 4d7 5s1",
+            Element::Rhodium => "[Kr] 4d8 5s1",
+            Element::Palladium => "[Kr] 4d10",
+            Element::Silver => "[Kr] 4d10 5s1",
+            Element::Cadmium => "[Kr] 4d10 5s2",
+            Element::Indium => "[Kr] 4d10 5s2 5p1",
+            Element::Tin => "[Kr] 4d10 5s2 5p2",
+            Element::Antimony => "[Kr] 4d10 5s2 5p3",
+            Element::Tellurium => "[Kr] 4d10 5s2 5p4",
+            Element::Iodine => "[Kr] 4d10 5s2 5p5",
+            Element::Xenon => "[Kr] 4d10 5s2 5p6",
+            Element::Caesium => "[Xe] 6s1",
+            Element::Barium => "[Xe] 6s2",
+            Element::Lanthanum => "[Xe] 5d1 6s2",
+            Element::Cerium => "[Xe] 4f1 5d1 6s2",
+            Element::Praseodymium => "[Xe] 4f3 6s2",
+            Element::Neodymium => "[Xe] 4f4 6s2",
+            Element::Promethium => "[Xe] 4f5 6s2",
+            Element::Samarium => "[Xe] 4f6 6s2",
+            Element::Europium => "[Xe] 4f7 6s2",
+            Element::Gadolinium => "[Xe] 4f7 5d1 6s2",
+            Element::Terbium => "[Xe] 4f9 6s2",
+            Element::Dysprosium => "[Xe] 4f10 6s2",
+            Element::Holmium => "[Xe] 4f11 6s2",
+            Element::Erbium => "[Xe] 4f12 6s2",
+            Element::Thulium => "[Xe] 4f13 6s2",
+            Element::Ytterbium => "[Xe] 4f14 6s2",
+            Element::Lutetium => "[Xe] 4f14 5d1 6s2",
+            Element::Hafnium => "[Xe] 4f14 5d2 6s2",
+            Element::Tantalum => "[Xe] 4f14 5d3 6s2",
+            Element::Tungsten => "[Xe] 4f14 5d4 6s2",
+            Element::Rhenium => "[Xe] 4f14 5d5 6s2",
+            Element::Osmium => "[Xe] 4f14 5d6 6s2",
+            Element::Iridium => "[Xe] 4f14 5d7 6s2",
+            Element::Platinum => "[Xe] 4f14 5d9 6s1",
+            Element::Gold => "[Xe] 4f14 5d10 6s1",
+            Element::Mercury => "[Xe] 4f14 5d10 6s2",
+            Element::Thallium => "[Xe] 4f14 5d10 6s2 6p1",
+            Element::Lead => "[Xe] 4f14 5d10 6s2 6p2",
+            Element::Bismuth => "[Xe] 4f14 5d10 6s2 6p3",
+            Element::Polonium => "[Xe] 4f14 5d10 6s2 6p4",
+            Element::Astatine => "[Xe] 4f14 5d10 6s2 6p5",
+            Element::Radon => "[Xe] 4f14 5d10 6s2 6p6",
+            Element::Francium => "[Rn] 7s1",
+            Element::Radium => "[Rn] 7s2",
+            Element::Actinium => "[Rn] 6d1 7s2",
+            Element::Thorium => "[Rn] 6d2 7s2",
+            Element::Protactinium => "[Rn] 5f2 6d1 7s2",
+            Element::Uranium => "[Rn] 5f3 6d1 7s2",
+            Element::Neptunium => "[Rn] 5f4 6d1 7s2",
+            Element::Plutonium => "[Rn] 5f6 7s2",
+            Element::Americium => "[Rn] 5f7 7s2",
+            Element::Curium => "[Rn] 5f7 6d1 7s2",
+            Element::Berkelium => "[Rn] 5f9 7s2",
+            Element::Californium => "[Rn] 5f10 7s2",
+            Element::Einsteinium => "[Rn] 5f11 7s2",
+            Element::Fermium => "[Rn] 5f12 7s2",
+            Element::Mendelevium => "[Rn] 5f13 7s2",
+            Element::Nobelium => "[Rn] 5f14 7s2",
+            Element::Lawrencium => "[Rn] 5f14 7s2 7p1",
+            Element::Rutherfordium => "[Rn] 5f14 6d2 7s2",
+            Element::Dubnium => "[Rn] 5f14 6d3 7s2",
+            Element::Seaborgium => "[Rn] 5f14 6d4 7s2",
+            Element::Bohrium => "[Rn] 5f14 6d5 7s2",
+            Element::Hassium => "[Rn] 5f14 6d6 7s2",
+            Element::Meitnerium => "[Rn] 5f14 6d7 7s2",
+            Element::Darmstadtium => "[Rn] 5f14 6d8 7s2",
+            Element::Roentgenium => "[Rn] 5f14 6d9 7s2",
+            Element::Copernicium => "[Rn] 5f14 6d10 7s2",
+            Element::Nihonium => "[Rn] 5f14 6d10 7s2 7p1",
+            Element::Flerovium => "[Rn] 5f14 6d10 7s2 7p2",
+            Element::Moscovium => "[Rn] 5f14 6d10 7s2 7p3",
+            Element::Livermorium => "[Rn] 5f14 6d10 7s2 7p4",
+            Element::Tennessine => "[Rn] 5f14 6d10 7s2 7p5",
+            Element::Oganesson => "[Rn] 5f14 6d10 7s2 7p6",
+        }
+    }
+
+    /// Returns `Element`'s category.
+    ///
+    /// Each element belongs to exactly one [`ElementCategory`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::{Element, ElementCategory};
+    ///
+    /// assert_eq!(Element::Iron.category(), ElementCategory::TransitionMetal);
+    /// assert_eq!(Element::Uranium.category(), ElementCategory::Actinide);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - *Lanthanum* to *Lutetium* are lanthanides and *Actinium* to
+    ///   *Lawrencium* are actinides (rather than transition metals).
+    /// - *Hydrogen* is a reactive nonmetal.
+    /// - Metalloids and nonmetals are those of [`is_metalloid`](Self::is_metalloid)
+    ///   and [`is_nonmetal`](Self::is_nonmetal), remaining p-block metals are
+    ///   post-transition metals.
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Periodic Table](https://en.wikipedia.org/wiki/Periodic_table)
+    pub fn category(&self) -> ElementCategory {
+        match self.atomic_number() {
+            57..=71 => ElementCategory::Lanthanide,
+            89..=103 => ElementCategory::Actinide,
+            _ if self.is_alkali_metal() => ElementCategory::AlkaliMetal,
+            _ if self.is_alkaline_earth_metal() => ElementCategory::AlkalineEarthMetal,
+            _ if self.is_noble_gas() => ElementCategory::NobleGas,
+            _ if self.is_metalloid() => ElementCategory::Metalloid,
+            _ if self.is_nonmetal() => ElementCategory::ReactiveNonmetal,
+            _ if matches!(self.group(), Some(3..=12)) => ElementCategory::TransitionMetal,
+            _ => ElementCategory::PostTransitionMetal,
+        }
+    }
+
+    /// Returns `Element`'s neighbors in the 18-column periodic table layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let neighbors = Element::Iron.neighbors();
+    /// assert_eq!(neighbors.up, None);
+    /// assert_eq!(neighbors.down, Some(Element::Ruthenium));
+    /// assert_eq!(neighbors.left, Some(Element::Manganese));
+    /// assert_eq!(neighbors.right, Some(Element::Cobalt));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Neighbors are computed from [`period`](Self::period) and
+    /// [`group`](Self::group): f-block elements (without group) have no
+    /// neighbors, and no element is found across periodic table edges/gaps.
+    pub fn neighbors(&self) -> PeriodicNeighbors {
+        let Some(group) = self.group() else {
+            return PeriodicNeighbors::default();
+        };
+        let period = self.period();
+        let find = |period: u32, group: u32| {
+            Self::iter()
+                .find(|element| element.period() == period && element.group() == Some(group))
+        };
+        PeriodicNeighbors {
+            up: find(period - 1, group),
+            down: find(period + 1, group),
+            left: find(period, group - 1),
+            right: find(period, group + 1),
+        }
+    }
+
+    /// Returns natural isotopic abundances of the element.
+    ///
+    /// Abundances are given as `(mass number, atom fraction)` pairs sorted by
+    /// mass number, fractions summing to 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let abundances = Element::Lithium.isotopic_abundances();
+    /// assert_eq!(abundances, [(6, 0.0759), (7, 0.9241)]);
+    /// assert!(Element::Technetium.isotopic_abundances().is_empty());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - representative isotopic composition for elements with a natural
+    ///   terrestrial composition
+    /// - an empty slice otherwise (e.g. *Technetium*, *Promethium*)
+    ///
+    /// # Notes
+    ///
+    /// Natural *Tantalum* with mass number 180 is the long-lived isomer Ta180m1.
+    ///
+    /// # References
+    ///
+    /// Meija, J., et al.
+    /// *Isotopic compositions of the elements 2013 (IUPAC Technical Report)*.
+    /// Pure and Applied Chemistry, vol. 88, no. 3, 2016, pp. 293-306.
+    /// <https://doi.org/10.1515/pac-2015-0503>
+    pub fn isotopic_abundances(&self) -> &[(u32, f64)] {
+        &ISOTOPIC_ABUNDANCES.get()[self.atomic_number() as usize]
+    }
+
+    /// Returns the most abundant natural isotope of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::{Element, Zai};
+    ///
+    /// assert_eq!(Element::Iron.most_abundant_isotope(), Some(Zai::new(26, 56, 0)));
+    /// assert_eq!(Element::Technetium.most_abundant_isotope(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(zai)` for elements with a natural terrestrial composition (see
+    ///   [`isotopic_abundances`](Self::isotopic_abundances))
+    /// - `None` otherwise
+    pub fn most_abundant_isotope(&self) -> Option<Zai> {
+        let (mass_number, _) = self
+            .isotopic_abundances()
+            .iter()
+            .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))?;
+        Some(Zai::new(self.atomic_number(), *mass_number, 0))
+    }
+
+    /// Returns standard atomic weight interval `[lower, upper]` of the element.
+    ///
+    /// Elements with a variable isotopic composition in normal materials have
+    /// their standard atomic weight given as an interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Carbon.atomic_weight_interval(), Some((12.0096, 12.0116)));
+    /// assert_eq!(Element::Fluorine.atomic_weight_interval(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some((lower, upper))` for the 14 elements with an interval standard
+    ///   atomic weight (H, Li, B, C, N, O, Mg, Si, S, Cl, Ar, Br, Tl, Pb)
+    /// - `None` otherwise (single value standard atomic weight, e.g.
+    ///   monoisotopic elements, or no standard atomic weight, e.g. synthetic
+    ///   elements)
+    ///
+    /// # References
+    ///
+    /// Prohaska, T., et al.
+    /// *Standard atomic weights of the elements 2021 (IUPAC Technical Report)*.
+    /// Pure and Applied Chemistry, vol. 94, no. 5, 2022, pp. 573-600.
+    /// <https://doi.org/10.1515/pac-2019-0603>
+    pub fn atomic_weight_interval(&self) -> Option<(f64, f64)> {
+        match self {
+            Element::Hydrogen => Some((1.00784, 1.00811)),
+            Element::Lithium => Some((6.938, 6.997)),
+            Element::Boron => Some((10.806, 10.821)),
+            Element::Carbon => Some((12.0096, 12.0116)),
+            Element::Nitrogen => Some((14.00643, 14.00728)),
+            Element::Oxygen => Some((15.99903, 15.99977)),
+            Element::Magnesium => Some((24.304, 24.307)),
+            Element::Silicon => Some((28.084, 28.086)),
+            Element::Sulfur => Some((32.059, 32.076)),
+            Element::Chlorine => Some((35.446, 35.457)),
+            Element::Argon => Some((39.792, 39.963)),
+            Element::Bromine => Some((79.901, 79.907)),
+            Element::Thallium => Some((204.382, 204.385)),
+            Element::Lead => Some((206.14, 207.94)),
+            _ => None,
+        }
+    }
+
+    /// Returns number of valence electrons of main-group elements.
+    ///
+    /// # Format
+    ///
+    /// Valence electrons are derived from [`group`](Self::group):
+    /// - group 1 → 1, group 2 → 2
+    /// - groups 13-18 → 3-8 (*Helium* excepted: 2)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Oxygen.valence_electrons(), Some(6));
+    /// assert_eq!(Element::Helium.valence_electrons(), Some(2));
+    /// assert_eq!(Element::Iron.valence_electrons(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(count)` for main-group elements (s-block and p-block)
+    /// - `None` for d-block and f-block elements (ambiguous number of valence
+    ///   electrons)
+    pub fn valence_electrons(&self) -> Option<u32> {
+        match self.group()? {
+            18 if *self == Element::Helium => Some(2),
+            group @ 1..=2 => Some(group),
+            group @ 13..=18 => Some(group - 10),
+            _ => None,
+        }
+    }
+
+    /// Returns `Element`'s discovery year.
+    ///
+    /// Discovery year is the year the element was first identified (or
+    /// synthesized) as such, as conventionally credited. Sources may differ by
+    /// a few years for elements whose identification and isolation are distinct
+    /// events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Helium.discovery_year(), Some(1868));
+    /// assert_eq!(Element::Technetium.discovery_year(), Some(1937));
+    /// assert_eq!(Element::Gold.discovery_year(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(year)` for elements discovered in the modern era
+    /// - `None` for elements known since antiquity or the middle ages (C, S,
+    ///   Fe, Cu, Zn, As, Ag, Sn, Sb, Au, Hg, Pb, Bi), rather than a guessed year
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Timeline of chemical element discoveries](https://en.wikipedia.org/wiki/Timeline_of_chemical_element_discoveries)
+    pub fn discovery_year(&self) -> Option<i32> {
+        match self {
+            Element::Carbon
+            | Element::Sulfur
+            | Element::Iron
+            | Element::Copper
+            | Element::Zinc
+            | Element::Arsenic
+            | Element::Silver
+            | Element::Tin
+            | Element::Antimony
+            | Element::Gold
+            | Element::Mercury
+            | Element::Lead
+            | Element::Bismuth => None,
+            Element::Hydrogen => Some(1766),
+            Element::Helium => Some(1868),
+            Element::Lithium => Some(1817),
+            Element::Beryllium => Some(1798),
+            Element::Boron => Some(1808),
+            Element::Nitrogen => Some(1772),
+            Element::Oxygen => Some(1774),
+            Element::Fluorine => Some(1886),
+            Element::Neon => Some(1898),
+            Element::Sodium => Some(1807),
+            Element::Magnesium => Some(1755),
+            Element::Aluminium => Some(1825),
+            Element::Silicon => Some(1824),
+            Element::Phosphorus => Some(1669),
+            Element::Chlorine => Some(1774),
+            Element::Argon => Some(1894),
+            Element::Potassium => Some(1807),
+            Element::Calcium => Some(1808),
+            Element::Scandium => Some(1879),
+            Element::Titanium => Some(1791),
+            Element::Vanadium => Some(1801),
+            Element::Chromium => Some(1797),
+            Element::Manganese => Some(1774),
+            Element::Cobalt => Some(1735),
+            Element::Nickel => Some(1751),
+            Element::Gallium => Some(1875),
+            Element::Germanium => Some(1886),
+            Element::Selenium => Some(1817),
+            Element::Bromine => Some(1826),
+            Element::Krypton => Some(1898),
+            Element::Rubidium => Some(1861),
+            Element::Strontium => Some(1790),
+            Element::Yttrium => Some(1794),
+            Element::Zirconium => Some(1789),
+            Element::Niobium => Some(1801),
+            Element::Molybdenum => Some(1778),
+            Element::Technetium => Some(1937),
+            Element::Ruthenium => Some(1844),
+            Element::Rhodium => Some(1804),
+            Element::Palladium => Some(1803),
+            Element::Cadmium => Some(1817),
+            Element::Indium => Some(1863),
+            Element::Tellurium => Some(1782),
+            Element::Iodine => Some(1811),
+            Element::Xenon => Some(1898),
+            Element::Caesium => Some(1860),
+            Element::Barium => Some(1772),
+            Element::Lanthanum => Some(1839),
+            Element::Cerium => Some(1803),
+            Element::Praseodymium => Some(1885),
+            Element::Neodymium => Some(1885),
+            Element::Promethium => Some(1945),
+            Element::Samarium => Some(1879),
+            Element::Europium => Some(1901),
+            Element::Gadolinium => Some(1880),
+            Element::Terbium => Some(1843),
+            Element::Dysprosium => Some(1886),
+            Element::Holmium => Some(1878),
+            Element::Erbium => Some(1843),
+            Element::Thulium => Some(1879),
+            Element::Ytterbium => Some(1878),
+            Element::Lutetium => Some(1907),
+            Element::Hafnium => Some(1923),
+            Element::Tantalum => Some(1802),
+            Element::Tungsten => Some(1783),
+            Element::Rhenium => Some(1925),
+            Element::Osmium => Some(1803),
+            Element::Iridium => Some(1803),
+            Element::Platinum => Some(1735),
+            Element::Thallium => Some(1861),
+            Element::Polonium => Some(1898),
+            Element::Astatine => Some(1940),
+            Element::Radon => Some(1899),
+            Element::Francium => Some(1939),
+            Element::Radium => Some(1898),
+            Element::Actinium => Some(1899),
+            Element::Thorium => Some(1829),
+            Element::Protactinium => Some(1913),
+            Element::Uranium => Some(1789),
+            Element::Neptunium => Some(1940),
+            Element::Plutonium => Some(1940),
+            Element::Americium => Some(1944),
+            Element::Curium => Some(1944),
+            Element::Berkelium => Some(1949),
+            Element::Californium => Some(1950),
+            Element::Einsteinium => Some(1952),
+            Element::Fermium => Some(1952),
+            Element::Mendelevium => Some(1955),
+            Element::Nobelium => Some(1966),
+            Element::Lawrencium => Some(1961),
+            Element::Rutherfordium => Some(1964),
+            Element::Dubnium => Some(1968),
+            Element::Seaborgium => Some(1974),
+            Element::Bohrium => Some(1981),
+            Element::Hassium => Some(1984),
+            Element::Meitnerium => Some(1982),
+            Element::Darmstadtium => Some(1994),
+            Element::Roentgenium => Some(1994),
+            Element::Copernicium => Some(1996),
+            Element::Nihonium => Some(2004),
+            Element::Flerovium => Some(1999),
+            Element::Moscovium => Some(2003),
+            Element::Livermorium => Some(2000),
+            Element::Tennessine => Some(2010),
+            Element::Oganesson => Some(2002),
+        }
+    }
+
+    /// Returns `Element`'s CPK color as an RGB triple.
+    ///
+    /// Colors follow the Jmol CPK palette, from *Hydrogen* (Z = 1) to
+    /// *Meitnerium* (Z = 109).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Hydrogen.cpk_color(), [255, 255, 255]);
+    /// assert_eq!(Element::Carbon.cpk_color(), [144, 144, 144]);
+    /// assert_eq!(Element::Oxygen.cpk_color(), [255, 13, 13]);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - Jmol color for elements from *Hydrogen* (Z = 1) to *Meitnerium* (Z = 109)
+    /// - gray `[128, 128, 128]` otherwise (no conventional color)
+    ///
+    /// # References
+    ///
+    /// [Jmol: Jmol colors](https://jmol.sourceforge.net/jscolors/)
+    pub fn cpk_color(&self) -> [u8; 3] {
+        match self {
+            Element::Hydrogen => [255, 255, 255],
+            Element::Helium => [217, 255, 255],
+            Element::Lithium => [204, 128, 255],
+            Element::Beryllium => [194, 255, 0],
+            Element::Boron => [255, 181, 181],
+            Element::Carbon => [144, 144, 144],
+            Element::Nitrogen => [48, 80, 248],
+            Element::Oxygen => [255, 13, 13],
+            Element::Fluorine => [144, 224, 80],
+            Element::Neon => [179, 227, 245],
+            Element::Sodium => [171, 92, 242],
+            Element::Magnesium => [138, 255, 0],
+            Element::Aluminium => [191, 166, 166],
+            Element::Silicon => [240, 200, 160],
+            Element::Phosphorus => [255, 128, 0],
+            Element::Sulfur => [255, 255, 48],
+            Element::Chlorine => [31, 240, 31],
+            Element::Argon => [128, 209, 227],
+            Element::Potassium => [143, 64, 212],
+            Element::Calcium => [61, 255, 0],
+            Element::Scandium => [230, 230, 230],
+            Element::Titanium => [191, 194, 199],
+            Element::Vanadium => [166, 166, 171],
+            Element::Chromium => [138, 153, 199],
+            Element::Manganese => [156, 122, 199],
+            Element::Iron => [224, 102, 51],
+            Element::Cobalt => [240, 144, 160],
+            Element::Nickel => [80, 208, 80],
+            Element::Copper => [200, 128, 51],
+            Element::Zinc => [125, 128, 176],
+            Element::Gallium => [194, 143, 143],
+            Element::Germanium => [102, 143, 143],
+            Element::Arsenic => [189, 128, 227],
+            Element::Selenium => [255, 161, 0],
+            Element::Bromine => [166, 41, 41],
+            Element::Krypton => [92, 184, 209],
+            Element::Rubidium => [112, 46, 176],
+            Element::Strontium => [0, 255, 0],
+            Element::Yttrium => [148, 255, 255],
+            Element::Zirconium => [148, 224, 224],
+            Element::Niobium => [115, 194, 201],
+            Element::Molybdenum => [84, 181, 181],
+            Element::Technetium => [59, 158, 158],
+            Element::Ruthenium => [36, 143, 143],
+            Element::Rhodium => [10, 125, 140],
+            Element::Palladium => [0, 105, 133],
+            Element::Silver => [192, 192, 192],
+            Element::Cadmium => [255, 217, 143],
+            Element::Indium => [166, 117, 115],
+            Element::Tin => [102, 128, 128],
+            Element::Antimony => [158, 99, 181],
+            Element::Tellurium => [212, 122, 0],
+            Element::Iodine => [148, 0, 148],
+            Element::Xenon => [66, 158, 176],
+            Element::Caesium => [87, 23, 143],
+            Element::Barium => [0, 201, 0],
+            Element::Lanthanum => [112, 212, 255],
+            Element::Cerium => [255, 255, 199],
+            Element::Praseodymium => [217, 255, 199],
+            Element::Neodymium => [199, 255, 199],
+            Element::Promethium => [163, 255, 199],
+            Element::Samarium => [143, 255, 199],
+            Element::Europium => [97, 255, 199],
+            Element::Gadolinium => [69, 255, 199],
+            Element::Terbium => [48, 255, 199],
+            Element::Dysprosium => [31, 255, 199],
+            Element::Holmium => [0, 255, 156],
+            Element::Erbium => [0, 230, 117],
+            Element::Thulium => [0, 212, 82],
+            Element::Ytterbium => [0, 191, 56],
+            Element::Lutetium => [0, 171, 36],
+            Element::Hafnium => [77, 194, 255],
+            Element::Tantalum => [77, 166, 255],
+            Element::Tungsten => [33, 148, 214],
+            Element::Rhenium => [38, 125, 171],
+            Element::Osmium => [38, 102, 150],
+            Element::Iridium => [23, 84, 135],
+            Element::Platinum => [208, 208, 224],
+            Element::Gold => [255, 209, 35],
+            Element::Mercury => [184, 184, 208],
+            Element::Thallium => [166, 84, 77],
+            Element::Lead => [87, 89, 97],
+            Element::Bismuth => [158, 79, 181],
+            Element::Polonium => [171, 92, 0],
+            Element::Astatine => [117, 79, 69],
+            Element::Radon => [66, 130, 150],
+            Element::Francium => [66, 0, 102],
+            Element::Radium => [0, 125, 0],
+            Element::Actinium => [112, 171, 250],
+            Element::Thorium => [0, 186, 255],
+            Element::Protactinium => [0, 161, 255],
+            Element::Uranium => [0, 143, 255],
+            Element::Neptunium => [0, 128, 255],
+            Element::Plutonium => [0, 107, 255],
+            Element::Americium => [84, 92, 242],
+            Element::Curium => [120, 92, 227],
+            Element::Berkelium => [138, 79, 227],
+            Element::Californium => [161, 54, 212],
+            Element::Einsteinium => [179, 31, 212],
+            Element::Fermium => [179, 31, 186],
+            Element::Mendelevium => [179, 13, 166],
+            Element::Nobelium => [189, 13, 135],
+            Element::Lawrencium => [199, 0, 102],
+            Element::Rutherfordium => [204, 0, 89],
+            Element::Dubnium => [209, 0, 79],
+            Element::Seaborgium => [217, 0, 69],
+            Element::Bohrium => [224, 0, 56],
+            Element::Hassium => [230, 0, 46],
+            Element::Meitnerium => [235, 0, 38],
+            _ => [128, 128, 128],
+        }
+    }
+
+    /// Returns `Element`'s mass number bounds `(min, max)` (inclusive).
+    ///
+    /// Bounds are the lightest and heaviest mass numbers among the element's
+    /// nuclides of the embedded atomic mass data (union of ENDF/B, JEFF and
+    /// JENDL decay sublibraries, see
+    /// [`AtomicMassLibrary`](crate::data::mass::AtomicMassLibrary)).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Hydrogen.mass_number_bounds(), (1, 7));
+    /// let (min, max) = Element::Uranium.mass_number_bounds();
+    /// assert!(min <= 232 && 242 <= max);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Bounds are much tighter than the range yielded by
+    /// [`Zai::iter_isotopes`], and can be used to restrict it:
+    ///
+    /// ```
+    /// use nkl::core::{Element, Zai};
+    ///
+    /// let (min, max) = Element::Carbon.mass_number_bounds();
+    /// let isotopes: Vec<Zai> = Zai::iter_isotopes(Element::Carbon)
+    ///     .filter(|zai| (min..=max).contains(&zai.mass_number()))
+    ///     .collect();
+    /// assert_eq!(isotopes.len(), (max - min + 1) as usize);
+    /// ```
+    pub fn mass_number_bounds(&self) -> (u32, u32) {
+        match self {
+            Element::Hydrogen => (1, 7),
+            Element::Helium => (3, 10),
+            Element::Lithium => (4, 12),
+            Element::Beryllium => (5, 16),
+            Element::Boron => (6, 19),
+            Element::Carbon => (8, 22),
+            Element::Nitrogen => (10, 25),
+            Element::Oxygen => (12, 28),
+            Element::Fluorine => (14, 31),
+            Element::Neon => (16, 34),
+            Element::Sodium => (18, 37),
+            Element::Magnesium => (19, 40),
+            Element::Aluminium => (21, 42),
+            Element::Silicon => (22, 44),
+            Element::Phosphorus => (24, 46),
+            Element::Sulfur => (26, 49),
+            Element::Chlorine => (28, 51),
+            Element::Argon => (30, 53),
+            Element::Potassium => (32, 55),
+            Element::Calcium => (34, 57),
+            Element::Scandium => (36, 60),
+            Element::Titanium => (38, 63),
+            Element::Vanadium => (40, 66),
+            Element::Chromium => (42, 70),
+            Element::Manganese => (44, 73),
+            Element::Iron => (45, 76),
+            Element::Cobalt => (47, 78),
+            Element::Nickel => (48, 82),
+            Element::Copper => (52, 84),
+            Element::Zinc => (54, 86),
+            Element::Gallium => (56, 89),
+            Element::Germanium => (58, 91),
+            Element::Arsenic => (60, 95),
+            Element::Selenium => (65, 96),
+            Element::Bromine => (67, 100),
+            Element::Krypton => (69, 102),
+            Element::Rubidium => (71, 105),
+            Element::Strontium => (73, 107),
+            Element::Yttrium => (76, 109),
+            Element::Zirconium => (78, 112),
+            Element::Niobium => (81, 115),
+            Element::Molybdenum => (83, 118),
+            Element::Technetium => (85, 121),
+            Element::Ruthenium => (87, 124),
+            Element::Rhodium => (89, 128),
+            Element::Palladium => (91, 131),
+            Element::Silver => (93, 133),
+            Element::Cadmium => (95, 136),
+            Element::Indium => (97, 138),
+            Element::Tin => (99, 140),
+            Element::Antimony => (103, 143),
+            Element::Tellurium => (105, 145),
+            Element::Iodine => (108, 148),
+            Element::Xenon => (109, 150),
+            Element::Caesium => (112, 152),
+            Element::Barium => (114, 154),
+            Element::Lanthanum => (117, 157),
+            Element::Cerium => (119, 160),
+            Element::Praseodymium => (121, 162),
+            Element::Neodymium => (124, 164),
+            Element::Promethium => (126, 166),
+            Element::Samarium => (128, 168),
+            Element::Europium => (130, 170),
+            Element::Gadolinium => (134, 172),
+            Element::Terbium => (135, 172),
+            Element::Dysprosium => (138, 173),
+            Element::Holmium => (140, 175),
+            Element::Erbium => (143, 177),
+            Element::Thulium => (145, 179),
+            Element::Ytterbium => (148, 181),
+            Element::Lutetium => (150, 184),
+            Element::Hafnium => (153, 188),
+            Element::Tantalum => (155, 192),
+            Element::Tungsten => (158, 192),
+            Element::Rhenium => (159, 194),
+            Element::Osmium => (162, 196),
+            Element::Iridium => (164, 202),
+            Element::Platinum => (166, 204),
+            Element::Gold => (169, 206),
+            Element::Mercury => (171, 211),
+            Element::Thallium => (176, 216),
+            Element::Lead => (178, 216),
+            Element::Bismuth => (184, 220),
+            Element::Polonium => (187, 220),
+            Element::Astatine => (191, 223),
+            Element::Radon => (193, 228),
+            Element::Francium => (199, 233),
+            Element::Radium => (202, 234),
+            Element::Actinium => (206, 236),
+            Element::Thorium => (209, 238),
+            Element::Protactinium => (212, 240),
+            Element::Uranium => (216, 242),
+            Element::Neptunium => (225, 244),
+            Element::Plutonium => (228, 247),
+            Element::Americium => (231, 249),
+            Element::Curium => (233, 252),
+            Element::Berkelium => (235, 254),
+            Element::Californium => (237, 256),
+            Element::Einsteinium => (240, 258),
+            Element::Fermium => (242, 260),
+            Element::Mendelevium => (245, 261),
+            Element::Nobelium => (249, 262),
+            Element::Lawrencium => (251, 266),
+            Element::Rutherfordium => (253, 265),
+            Element::Dubnium => (255, 270),
+            Element::Seaborgium => (258, 271),
+            Element::Bohrium => (260, 274),
+            Element::Hassium => (263, 275),
+            Element::Meitnerium => (265, 278),
+            Element::Darmstadtium => (267, 281),
+            Element::Roentgenium => (272, 282),
+            Element::Copernicium => (277, 285),
+            Element::Nihonium => (278, 286),
+            Element::Flerovium => (285, 289),
+            Element::Moscovium => (287, 290),
+            Element::Livermorium => (290, 293),
+            Element::Tennessine => (293, 294),
+            Element::Oganesson => (294, 294),
+        }
+    }
+
+    /// Returns `Element`'s electronegativity (Pauling scale).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::Fluorine.electronegativity(), Some(3.98));
+    /// assert_eq!(Element::Helium.electronegativity(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(electronegativity)` for elements from *Hydrogen* (Z = 1) to
+    ///   *Nobelium* (Z = 102)
+    /// - `None` otherwise, and for *Helium*, *Neon* and *Argon* (no known
+    ///   compounds)
+    ///
+    /// # References
+    ///
+    /// - Allred, A. L.
+    ///   *Electronegativity values from thermochemical data*.
+    ///   Journal of Inorganic and Nuclear Chemistry, vol. 17, no. 3-4, 1961, pp. 215-221.
+    ///   <https://doi.org/10.1016/0022-1902(61)80142-5>
+    /// - [Wikipedia: Electronegativities of the elements (data page)](https://en.wikipedia.org/wiki/Electronegativities_of_the_elements_(data_page))
+    pub fn electronegativity(&self) -> Option<f64> {
+        match self {
+            Element::Hydrogen => Some(2.20),
+            Element::Lithium => Some(0.98),
+            Element::Beryllium => Some(1.57),
+            Element::Boron => Some(2.04),
+            Element::Carbon => Some(2.55),
+            Element::Nitrogen => Some(3.04),
+            Element::Oxygen => Some(3.44),
+            Element::Fluorine => Some(3.98),
+            Element::Sodium => Some(0.93),
+            Element::Magnesium => Some(1.31),
+            Element::Aluminium => Some(1.61),
+            Element::Silicon => Some(1.90),
+            Element::Phosphorus => Some(2.19),
+            Element::Sulfur => Some(2.58),
+            Element::Chlorine => Some(3.16),
+            Element::Potassium => Some(0.82),
+            Element::Calcium => Some(1.00),
+            Element::Scandium => Some(1.36),
+            Element::Titanium => Some(1.54),
+            Element::Vanadium => Some(1.63),
+            Element::Chromium => Some(1.66),
+            Element::Manganese => Some(1.55),
+            Element::Iron => Some(1.83),
+            Element::Cobalt => Some(1.88),
+            Element::Nickel => Some(1.91),
+            Element::Copper => Some(1.90),
+            Element::Zinc => Some(1.65),
+            Element::Gallium => Some(1.81),
+            Element::Germanium => Some(2.01),
+            Element::Arsenic => Some(2.18),
+            Element::Selenium => Some(2.55),
+            Element::Bromine => Some(2.96),
+            Element::Krypton => Some(3.00),
+            Element::Rubidium => Some(0.82),
+            Element::Strontium => Some(0.95),
+            Element::Yttrium => Some(1.22),
+            Element::Zirconium => Some(1.33),
+            Element::Niobium => Some(1.6),
+            Element::Molybdenum => Some(2.16),
+            Element::Technetium => Some(1.9),
+            Element::Ruthenium => Some(2.2),
+            Element::Rhodium => Some(2.28),
+            Element::Palladium => Some(2.20),
+            Element::Silver => Some(1.93),
+            Element::Cadmium => Some(1.69),
+            Element::Indium => Some(1.78),
+            Element::Tin => Some(1.96),
+            Element::Antimony => Some(2.05),
+            Element::Tellurium => Some(2.1),
+            Element::Iodine => Some(2.66),
+            Element::Xenon => Some(2.6),
+            Element::Caesium => Some(0.79),
+            Element::Barium => Some(0.89),
+            Element::Lanthanum => Some(1.10),
+            Element::Cerium => Some(1.12),
+            Element::Praseodymium => Some(1.13),
+            Element::Neodymium => Some(1.14),
+            Element::Promethium => Some(1.13),
+            Element::Samarium => Some(1.17),
+            Element::Europium => Some(1.2),
+            Element::Gadolinium => Some(1.2),
+            Element::Terbium => Some(1.1),
+            Element::Dysprosium => Some(1.22),
+            Element::Holmium => Some(1.23),
+            Element::Erbium => Some(1.24),
+            Element::Thulium => Some(1.25),
+            Element::Ytterbium => Some(1.1),
+            Element::Lutetium => Some(1.27),
+            Element::Hafnium => Some(1.3),
+            Element::Tantalum => Some(1.5),
+            Element::Tungsten => Some(2.36),
+            Element::Rhenium => Some(1.9),
+            Element::Osmium => Some(2.2),
+            Element::Iridium => Some(2.20),
+            Element::Platinum => Some(2.28),
+            Element::Gold => Some(2.54),
+            Element::Mercury => Some(2.00),
+            Element::Thallium => Some(1.62),
+            Element::Lead => Some(2.33),
+            Element::Bismuth => Some(2.02),
+            Element::Polonium => Some(2.0),
+            Element::Astatine => Some(2.2),
+            Element::Radon => Some(2.2),
+            Element::Francium => Some(0.7),
+            Element::Radium => Some(0.9),
+            Element::Actinium => Some(1.1),
+            Element::Thorium => Some(1.3),
+            Element::Protactinium => Some(1.5),
+            Element::Uranium => Some(1.38),
+            Element::Neptunium => Some(1.36),
+            Element::Plutonium => Some(1.28),
+            Element::Americium => Some(1.13),
+            Element::Curium => Some(1.28),
+            Element::Berkelium => Some(1.3),
+            Element::Californium => Some(1.3),
+            Element::Einsteinium => Some(1.3),
+            Element::Fermium => Some(1.3),
+            Element::Mendelevium => Some(1.3),
+            Element::Nobelium => Some(1.3),
+            _ => None,
+        }
+    }
+
+    /// Returns `Element`'s atomic mass \[u\] for its natural isotopic composition.
+    ///
+    /// # Format
+    ///
+    /// Atomic mass is the abundance weighted mean of isotopes atomic masses:
+    ///
+    /// ```text
+    /// M = Σ x(A) × m(A)
+    /// ```
+    ///
+    /// with:
+    /// - `x(A)`: isotopic abundance (see [`isotopic_abundances`](Self::isotopic_abundances))
+    /// - `m(A)`: atomic mass of the isotope (see [`atomic_mass`](crate::data::mass::atomic_mass))
+    ///
+    /// # Notes
+    ///
+    /// This is computed from the embedded abundances and ENDF/B isotope
+    /// masses, and is *not* the IUPAC standard atomic weight (see
+    /// [`atomic_weight_interval`](Self::atomic_weight_interval)), although both
+    /// agree closely for most elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert!((Element::Iron.natural_atomic_mass().unwrap() - 55.845).abs() < 1e-2);
+    /// assert_eq!(Element::Technetium.natural_atomic_mass(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(mass)` for elements with a natural terrestrial composition
+    /// - `None` otherwise (e.g. *Technetium*, *Promethium*)
+    pub fn natural_atomic_mass(&self) -> Option<f64> {
+        let abundances = self.isotopic_abundances();
+        if abundances.is_empty() {
+            return None;
+        }
+        abundances
+            .iter()
+            .map(|&(mass_number, abundance)| {
+                let zai = Zai::new(self.atomic_number(), mass_number, 0);
+                Some(abundance * crate::data::mass::atomic_mass(zai)?)
+            })
+            .sum()
+    }
+
+    /// Compares elements by atomic number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let mut elements = vec![Element::Uranium, Element::Hydrogen, Element::Iron];
+    /// elements.sort_by(Element::cmp_by_atomic_number);
+    /// assert_eq!(elements, [Element::Hydrogen, Element::Iron, Element::Uranium]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// This ordering is the same as `Element`'s [`Ord`] implementation.
+    pub fn cmp_by_atomic_number(&self, other: &Element) -> Ordering {
+        self.atomic_number().cmp(&other.atomic_number())
+    }
+
+    /// Compares elements by atomic mass (see [`natural_atomic_mass`](Self::natural_atomic_mass)).
+    ///
+    /// Elements without atomic mass are sorted last, by atomic number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// // Argon is heavier than Potassium
+    /// let mut elements = vec![Element::Technetium, Element::Argon, Element::Potassium];
+    /// elements.sort_by(Element::cmp_by_atomic_mass);
+    /// assert_eq!(elements, [Element::Potassium, Element::Argon, Element::Technetium]);
+    /// ```
+    pub fn cmp_by_atomic_mass(&self, other: &Element) -> Ordering {
+        cmp_none_last(self.natural_atomic_mass(), other.natural_atomic_mass())
+            .then_with(|| self.cmp_by_atomic_number(other))
+    }
+
+    /// Compares elements by electronegativity (see
+    /// [`electronegativity`](Self::electronegativity)).
+    ///
+    /// Elements without electronegativity are sorted last, by atomic number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// let mut elements = vec![Element::Helium, Element::Oxygen, Element::Sodium];
+    /// elements.sort_by(Element::cmp_by_electronegativity);
+    /// assert_eq!(elements, [Element::Sodium, Element::Oxygen, Element::Helium]);
+    /// ```
+    pub fn cmp_by_electronegativity(&self, other: &Element) -> Ordering {
+        cmp_none_last(self.electronegativity(), other.electronegativity())
+            .then_with(|| self.cmp_by_atomic_number(other))
+    }
+}
+
+impl FromStr for Element {
+    type Err = ParseElementError;
+
+    /// Parses `Element` from its symbol or its name (case insensitive).
+    ///
+    /// Symbol is tried first, then name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!("Fe".parse::<Element>().unwrap(), Element::Iron);
+    /// assert_eq!("Iron".parse::<Element>().unwrap(), Element::Iron);
+    /// assert!("Xx".parse::<Element>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::from_symbol(s) {
+            Some(element) => Ok(element),
+            None => Self::from_name(s).ok_or(ParseElementError),
+        }
+    }
+}
+
+impl TryFrom<&str> for Element {
+    type Error = ParseElementError;
+
+    /// Converts symbol or name (case insensitive) into `Element`.
+    ///
+    /// Same as [`Element::from_str`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::try_from("Fe").unwrap(), Element::Iron);
+    /// assert_eq!(Element::try_from("Iron").unwrap(), Element::Iron);
+    /// assert!(Element::try_from("Xx").is_err());
+    /// ```
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl TryFrom<u32> for Element {
+    type Error = TryFromAtomicNumberError;
+
+    /// Converts atomic number into `Element`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Element;
+    ///
+    /// assert_eq!(Element::try_from(26).unwrap(), Element::Iron);
+    /// assert!(Element::try_from(0).is_err());
+    /// assert!(Element::try_from(119).is_err());
+    /// ```
+    fn try_from(atomic_number: u32) -> Result<Self, Self::Error> {
+        Self::from_atomic_number(atomic_number).ok_or(TryFromAtomicNumberError)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Element {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.symbol())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Element {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ElementVisitor;
+
+        impl serde::de::Visitor<'_> for ElementVisitor {
+            type Value = Element;
+
+            fn expecting(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(fmt, "an element symbol")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, symbol: &str) -> Result<Element, E> {
+                Element::from_symbol(symbol)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(symbol), &self))
+            }
+        }
+
+        deserializer.deserialize_str(ElementVisitor)
+    }
+}
+
+/// Chemical element category.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::{Element, ElementCategory};
+///
+/// match Element::Sodium.category() {
+///     ElementCategory::AlkaliMetal => println!("alkali metal"),
+///     _ => println!("other"),
+/// }
+/// ```
+///
+/// # References
+///
+/// [Wikipedia: Periodic Table](https://en.wikipedia.org/wiki/Periodic_table)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ElementCategory {
+    /// Alkali metal (group 1, except *Hydrogen*).
+    AlkaliMetal,
+    /// Alkaline earth metal (group 2).
+    AlkalineEarthMetal,
+    /// Transition metal (groups 3 to 12, except lanthanides and actinides).
+    TransitionMetal,
+    /// Post-transition metal.
+    PostTransitionMetal,
+    /// Metalloid.
+    Metalloid,
+    /// Reactive nonmetal.
+    ReactiveNonmetal,
+    /// Noble gas (group 18).
+    NobleGas,
+    /// Lanthanide.
+    Lanthanide,
+    /// Actinide.
+    Actinide,
+}
+
+/// Neighbors of an [`Element`] in the 18-column periodic table layout.
+///
+/// Returned by [`Element::neighbors`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PeriodicNeighbors {
+    /// Neighbor in previous period, same group.
+    pub up: Option<Element>,
+    /// Neighbor in next period, same group.
+    pub down: Option<Element>,
+    /// Neighbor in previous group, same period.
+    pub left: Option<Element>,
+    /// Neighbor in next group, same period.
+    pub right: Option<Element>,
+}
+
+/// Iterator over all elements ordered by atomic number.
+///
+/// Returned by [`Element::iter`].
+#[derive(Clone, Debug)]
+pub struct ElementIter(std::slice::Iter<'static, Element>);
+
+impl Iterator for ElementIter {
+    type Item = Element;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for ElementIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().copied()
+    }
+}
+
+impl ExactSizeIterator for ElementIter {}
+
+impl FusedIterator for ElementIter {}
+
+// Compares optional values, `None` being greater than any value.
+fn cmp_none_last(lhs: Option<f64>, rhs: Option<f64>) -> Ordering {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => lhs.total_cmp(&rhs),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Error returned when parsing an [`Element`] from a string fails.
+#[derive(Debug)]
+pub struct ParseElementError;
+
+impl Display for ParseElementError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "parse element error")
+    }
+}
+
+impl Error for ParseElementError {}
+
+/// Error returned when converting an atomic number into an [`Element`] fails.
+#[derive(Debug)]
+pub struct TryFromAtomicNumberError;
+
+impl Display for TryFromAtomicNumberError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "atomic number out of range")
+    }
+}
+
+impl Error for TryFromAtomicNumberError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_range() {
+        for element in Element::iter() {
+            if let Some(group) = element.group() {
+                assert!((1..=18).contains(&group), "{}", element.name());
+            }
+        }
+    }
+
+    #[test]
+    fn group_selenium() {
+        assert_eq!(Element::Selenium.group(), Some(16));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("Fe".parse::<Element>().unwrap(), Element::Iron);
+        assert_eq!("Iron".parse::<Element>().unwrap(), Element::Iron);
+        assert_eq!("fe".parse::<Element>().unwrap(), Element::Iron);
+        assert_eq!("FE".parse::<Element>().unwrap(), Element::Iron);
+        assert_eq!("iRoN".parse::<Element>().unwrap(), Element::Iron);
+        assert!("Xx".parse::<Element>().is_err());
+        assert!("".parse::<Element>().is_err());
+    }
+
+    #[test]
+    fn metallic_classification_partition() {
+        for element in Element::iter() {
+            let count = [
+                element.is_metal(),
+                element.is_nonmetal(),
+                element.is_metalloid(),
+            ]
+            .iter()
+            .filter(|&&predicate| predicate)
+            .count();
+            assert_eq!(count, 1, "{}", element.name());
+        }
+    }
+
+    #[test]
+    fn try_from_atomic_number() {
+        for element in Element::iter() {
+            let converted: Element = element.atomic_number().try_into().unwrap();
+            assert_eq!(converted, element);
+        }
+        assert!(Element::try_from(0).is_err());
+        assert!(Element::try_from(119).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let json = serde_json::to_string(&Element::Uranium).unwrap();
+        assert_eq!(json, "\"U\"");
+        let element: Element = serde_json::from_str(&json).unwrap();
+        assert_eq!(element, Element::Uranium);
+        let element: Element = serde_json::from_str("\"fe\"").unwrap();
+        assert_eq!(element, Element::Iron);
+        assert!(serde_json::from_str::<Element>("\"Xx\"").is_err());
+        assert!(serde_json::from_str::<Element>("92").is_err());
+    }
+
+    #[test]
+    fn is_radioactive() {
+        assert!(!Element::Hydrogen.is_radioactive());
+        assert!(!Element::Iron.is_radioactive());
+        assert!(!Element::Lead.is_radioactive());
+        assert!(Element::Technetium.is_radioactive());
+        assert!(Element::Promethium.is_radioactive());
+        assert!(Element::Bismuth.is_radioactive());
+        assert!(Element::Uranium.is_radioactive());
+        assert!(Element::Oganesson.is_radioactive());
+    }
+
+    #[test]
+    fn covalent_radius() {
+        assert_eq!(Element::Hydrogen.covalent_radius(), Some(31.));
+        assert_eq!(Element::Carbon.covalent_radius(), Some(76.));
+        assert_eq!(Element::Curium.covalent_radius(), Some(169.));
+        assert_eq!(Element::Berkelium.covalent_radius(), None);
+        assert_eq!(Element::Oganesson.covalent_radius(), None);
+    }
+
+    #[test]
+    fn electron_configuration() {
+        assert_eq!(Element::Hydrogen.electron_configuration(), "1s1");
+        assert_eq!(Element::Iron.electron_configuration(), "[Ar] 3d6 4s2");
+        assert_eq!(Element::Copper.electron_configuration(), "[Ar] 3d10 4s1");
+        assert_eq!(Element::Chromium.electron_configuration(), "[Ar] 3d5 4s1");
+        assert_eq!(
+            Element::Oganesson.electron_configuration(),
+            "[Rn] 5f14 6d10 7s2 7p6"
+        );
+    }
+
+    #[test]
+    fn category() {
+        for element in Element::iter() {
+            let category = element.category();
+            assert_eq!(
+                element.is_alkali_metal(),
+                category == ElementCategory::AlkaliMetal
+            );
+            assert_eq!(
+                element.is_noble_gas(),
+                category == ElementCategory::NobleGas
+            );
+            assert_eq!(
+                element.is_metalloid(),
+                category == ElementCategory::Metalloid
+            );
+        }
+        assert_eq!(
+            Element::Hydrogen.category(),
+            ElementCategory::ReactiveNonmetal
+        );
+        assert_eq!(Element::Sodium.category(), ElementCategory::AlkaliMetal);
+        assert_eq!(
+            Element::Calcium.category(),
+            ElementCategory::AlkalineEarthMetal
+        );
+        assert_eq!(Element::Iron.category(), ElementCategory::TransitionMetal);
+        assert_eq!(
+            Element::Lead.category(),
+            ElementCategory::PostTransitionMetal
+        );
+        assert_eq!(Element::Silicon.category(), ElementCategory::Metalloid);
+        assert_eq!(
+            Element::Bromine.category(),
+            ElementCategory::ReactiveNonmetal
+        );
+        assert_eq!(Element::Neon.category(), ElementCategory::NobleGas);
+        assert_eq!(Element::Lutetium.category(), ElementCategory::Lanthanide);
+        assert_eq!(Element::Uranium.category(), ElementCategory::Actinide);
+    }
+
+    #[test]
+    fn neighbors() {
+        let neighbors = Element::Iron.neighbors();
+        assert_eq!(neighbors.up, None);
+        assert_eq!(neighbors.down, Some(Element::Ruthenium));
+        assert_eq!(neighbors.left, Some(Element::Manganese));
+        assert_eq!(neighbors.right, Some(Element::Cobalt));
+        let neighbors = Element::Hydrogen.neighbors();
+        assert_eq!(neighbors.up, None);
+        assert_eq!(neighbors.down, Some(Element::Lithium));
+        assert_eq!(neighbors.left, None);
+        assert_eq!(neighbors.right, None);
+        let neighbors = Element::Silicon.neighbors();
+        assert_eq!(neighbors.up, Some(Element::Carbon));
+        assert_eq!(neighbors.down, Some(Element::Germanium));
+        assert_eq!(neighbors.left, Some(Element::Aluminium));
+        assert_eq!(neighbors.right, Some(Element::Phosphorus));
+        assert_eq!(Element::Oganesson.neighbors().down, None);
+        assert_eq!(Element::Uranium.neighbors(), PeriodicNeighbors::default());
+    }
+
+    #[test]
+    fn isotopic_abundances() {
+        let mut count = 0;
+        for element in Element::iter() {
+            let abundances = element.isotopic_abundances();
+            if abundances.is_empty() {
+                continue;
+            }
+            count += 1;
+            let total: f64 = abundances.iter().map(|(_, fraction)| fraction).sum();
+            assert!((total - 1.).abs() < 2e-4, "{}", element.name());
+            assert!(abundances.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+        assert_eq!(count, 84);
+        assert!(Element::Technetium.isotopic_abundances().is_empty());
+        assert!(Element::Plutonium.isotopic_abundances().is_empty());
+    }
+
+    #[test]
+    fn most_abundant_isotope() {
+        assert_eq!(
+            Element::Hydrogen.most_abundant_isotope(),
+            Some(Zai::new(1, 1, 0))
+        );
+        assert_eq!(
+            Element::Uranium.most_abundant_isotope(),
+            Some(Zai::new(92, 238, 0))
+        );
+        assert_eq!(
+            Element::Tantalum.most_abundant_isotope(),
+            Some(Zai::new(73, 181, 0))
+        );
+        assert_eq!(Element::Technetium.most_abundant_isotope(), None);
+    }
+
+    #[test]
+    fn atomic_weight_interval() {
+        assert_eq!(
+            Element::Carbon.atomic_weight_interval(),
+            Some((12.0096, 12.0116))
+        );
+        assert_eq!(Element::Fluorine.atomic_weight_interval(), None);
+        assert_eq!(Element::Technetium.atomic_weight_interval(), None);
+        let count = Element::iter()
+            .filter_map(|element| element.atomic_weight_interval())
+            .inspect(|(lower, upper)| assert!(lower < upper))
+            .count();
+        assert_eq!(count, 14);
+    }
+
+    #[test]
+    fn all() {
+        let elements = Element::all();
+        assert_eq!(elements.len(), 118);
+        assert_eq!(elements[25], Element::Iron);
+        for (index, element) in elements.iter().enumerate() {
+            assert_eq!(element.atomic_number() as usize, index + 1);
+        }
+    }
+
+    #[test]
+    fn atomic_number_bounds() {
+        let min = Element::MIN_ATOMIC_NUMBER;
+        let max = Element::MAX_ATOMIC_NUMBER;
+        assert!(Element::from_atomic_number(min).is_some());
+        assert!(Element::from_atomic_number(min - 1).is_none());
+        assert!(Element::from_atomic_number(max).is_some());
+        assert!(Element::from_atomic_number(max + 1).is_none());
+        assert_eq!(Element::iter().count() as u32, max - min + 1);
+    }
+
+    #[test]
+    fn valence_electrons() {
+        assert_eq!(Element::Hydrogen.valence_electrons(), Some(1));
+        assert_eq!(Element::Helium.valence_electrons(), Some(2));
+        assert_eq!(Element::Sodium.valence_electrons(), Some(1));
+        assert_eq!(Element::Calcium.valence_electrons(), Some(2));
+        assert_eq!(Element::Carbon.valence_electrons(), Some(4));
+        assert_eq!(Element::Oxygen.valence_electrons(), Some(6));
+        assert_eq!(Element::Neon.valence_electrons(), Some(8));
+        assert_eq!(Element::Iron.valence_electrons(), None);
+        assert_eq!(Element::Uranium.valence_electrons(), None);
+    }
+
+    #[test]
+    fn from_name_alternate_spellings() {
+        for (names, element) in [
+            (["Aluminium", "Aluminum"], Element::Aluminium),
+            (["Caesium", "Cesium"], Element::Caesium),
+            (["Sulfur", "Sulphur"], Element::Sulfur),
+        ] {
+            for name in names {
+                assert_eq!(Element::from_name(name), Some(element));
+                assert_eq!(Element::from_name(&name.to_uppercase()), Some(element));
+            }
+            assert_eq!(element.name(), names[0]);
+        }
+    }
+
+    #[test]
+    fn discovery_year() {
+        assert_eq!(Element::Helium.discovery_year(), Some(1868));
+        assert_eq!(Element::Technetium.discovery_year(), Some(1937));
+        assert_eq!(Element::Gold.discovery_year(), None);
+        assert_eq!(Element::Iron.discovery_year(), None);
+        assert_eq!(Element::Oganesson.discovery_year(), Some(2002));
+    }
+
+    #[test]
+    fn cpk_color() {
+        assert_eq!(Element::Hydrogen.cpk_color(), [255, 255, 255]);
+        assert_eq!(Element::Oxygen.cpk_color(), [255, 13, 13]);
+        assert_eq!(Element::Nitrogen.cpk_color(), [48, 80, 248]);
+        assert_eq!(Element::Meitnerium.cpk_color(), [235, 0, 38]);
+        assert_eq!(Element::Oganesson.cpk_color(), [128, 128, 128]);
+    }
+
+    #[test]
+    fn iter_period() {
+        let period: Vec<Element> = Element::iter_period(1).collect();
+        assert_eq!(period, [Element::Hydrogen, Element::Helium]);
+        assert_eq!(Element::iter_period(2).count(), 8);
+        assert_eq!(Element::iter_period(7).count(), 32);
+        assert_eq!(Element::iter_period(8).count(), 0);
+    }
+
+    #[test]
+    fn iter_group() {
+        let group: Vec<Element> = Element::iter_group(1).collect();
+        assert_eq!(
+            group,
+            [
+                Element::Hydrogen,
+                Element::Lithium,
+                Element::Sodium,
+                Element::Potassium,
+                Element::Rubidium,
+                Element::Caesium,
+                Element::Francium,
+            ]
+        );
+        assert!(Element::iter_group(3).all(|element| element.block() != "f"));
+        assert_eq!(Element::iter_group(19).count(), 0);
+    }
+
+    #[test]
+    fn static_strings() {
+        let symbol: &'static str = Element::Iron.symbol();
+        let name: &'static str = Element::Iron.name();
+        let block: &'static str = Element::Iron.block();
+        let configuration: &'static str = Element::Iron.electron_configuration();
+        assert_eq!(symbol, "Fe");
+        assert_eq!(name, "Iron");
+        assert_eq!(block, "d");
+        assert_eq!(configuration, "[Ar] 3d6 4s2");
+    }
+
+    #[test]
+    fn count() {
+        assert_eq!(Element::iter().count(), Element::COUNT);
+        assert_eq!(Element::COUNT, Element::MAX_ATOMIC_NUMBER as usize);
+        assert_eq!(Element::lightest(), Element::Hydrogen);
+        assert_eq!(Element::heaviest(), Element::Oganesson);
+        assert_eq!(
+            Element::lightest().atomic_number(),
+            Element::MIN_ATOMIC_NUMBER
+        );
+        assert_eq!(
+            Element::heaviest().atomic_number(),
+            Element::MAX_ATOMIC_NUMBER
+        );
+    }
+
+    #[test]
+    fn iter_rev() {
+        let mut iter = Element::iter().rev();
+        assert_eq!(iter.next(), Some(Element::Oganesson));
+        assert_eq!(iter.next(), Some(Element::Tennessine));
+        assert_eq!(iter.len(), Element::COUNT - 2);
+        assert_eq!(Element::iter().rev().next_back(), Some(Element::Hydrogen));
+    }
+
+    #[test]
+    fn mass_number_bounds() {
+        assert_eq!(Element::Hydrogen.mass_number_bounds(), (1, 7));
+        assert_eq!(Element::Uranium.mass_number_bounds(), (216, 242));
+        for element in Element::iter() {
+            let (min, max) = element.mass_number_bounds();
+            assert!(element.atomic_number() <= min, "{}", element.name());
+            assert!(min <= max, "{}", element.name());
+            assert!(
+                max <= 3 * element.atomic_number() + 20,
+                "{}",
+                element.name()
+            );
+        }
+    }
+
+    #[test]
+    fn try_from_str() {
+        assert_eq!(Element::try_from("U").unwrap(), Element::Uranium);
+        assert_eq!(Element::try_from("uranium").unwrap(), Element::Uranium);
+        assert!(Element::try_from("Xx").is_err());
+        assert!(Element::try_from("").is_err());
+    }
+
+    #[test]
+    fn electronegativity() {
+        assert_eq!(Element::Hydrogen.electronegativity(), Some(2.2));
+        assert_eq!(Element::Caesium.electronegativity(), Some(0.79));
+        assert_eq!(Element::Nobelium.electronegativity(), Some(1.3));
+        assert_eq!(Element::Argon.electronegativity(), None);
+        assert_eq!(Element::Lawrencium.electronegativity(), None);
+        let max = Element::iter()
+            .filter_map(|element| element.electronegativity())
+            .fold(0., f64::max);
+        assert_eq!(Element::Fluorine.electronegativity(), Some(max));
+    }
+
+    #[test]
+    fn natural_atomic_mass() {
+        let close = |element: Element, mass: f64| {
+            (element.natural_atomic_mass().unwrap() - mass).abs() < 1e-2
+        };
+        assert!(close(Element::Hydrogen, 1.008));
+        assert!(close(Element::Carbon, 12.011));
+        assert!(close(Element::Uranium, 238.029));
+        assert!(Element::Promethium.natural_atomic_mass().is_none());
+        assert!(Element::iter()
+            .filter(|element| !element.isotopic_abundances().is_empty())
+            .all(|element| element.natural_atomic_mass().is_some()));
+    }
+
+    #[test]
+    fn cmp_by() {
+        let mut elements = vec![
+            Element::Helium,
+            Element::Fluorine,
+            Element::Iron,
+            Element::Caesium,
+            Element::Oxygen,
+            Element::Neon,
+        ];
+        elements.sort_by(Element::cmp_by_electronegativity);
+        assert_eq!(
+            elements,
+            [
+                Element::Caesium,
+                Element::Iron,
+                Element::Oxygen,
+                Element::Fluorine,
+                Element::Helium,
+                Element::Neon
+            ]
+        );
+        elements.sort_by(Element::cmp_by_atomic_mass);
+        assert_eq!(elements[0], Element::Helium);
+        assert_eq!(elements[5], Element::Caesium);
+        elements.sort_by(Element::cmp_by_atomic_number);
+        assert!(elements.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn mass_number_bounds_libraries() {
+        use crate::data::mass::{
+            AtomicMassLibrary, EndfbAtomicMassLibrary, JeffAtomicMassLibrary,
+            JendlAtomicMassLibrary,
+        };
+        let mut bounds = std::collections::HashMap::new();
+        for library in [
+            &EndfbAtomicMassLibrary as &dyn AtomicMassLibrary,
+            &JeffAtomicMassLibrary,
+            &JendlAtomicMassLibrary,
+        ] {
+            for (zai, _) in library.iter() {
+                let (min, max) = bounds
+                    .entry(zai.element())
+                    .or_insert((zai.mass_number(), zai.mass_number()));
+                *min = zai.mass_number().min(*min);
+                *max = zai.mass_number().max(*max);
+            }
+        }
+        for element in Element::iter() {
+            assert_eq!(
+                Some(&element.mass_number_bounds()),
+                bounds.get(&element),
+                "{}",
+                element.name()
+            );
+        }
+    }
 }
@@ -1,4 +1,20 @@
-use crate::core::Element;
+use std::{cmp::Ordering, collections::HashSet, error::Error, fmt::Display};
+
+use crate::core::{decay, DecayMode, Element};
+use crate::lazy::Lazy;
+
+static STABLE_NUCLIDES: Lazy<HashSet<Zai>> = Lazy::new(|| {
+    let source = include_str!("../../data/stable_nuclides/stable");
+    source
+        .lines()
+        .map(|line| {
+            let name = line.split_whitespace().nth(3);
+            name.and_then(Zai::from_name)
+                .expect("invalid embedded stable nuclides data")
+        })
+        .collect()
+});
+
 /// Nuclide identifier `ZAI`.
 ///
 /// - `Z`: *atomic number* / proton number / nuclear charge number
@@ -22,6 +38,10 @@ use crate::core::Element;
 /// assert_eq!(h1.isomeric_state_number(), 0);
 /// assert_eq!(h1.element(), Element::Hydrogen)
 /// ```
+///
+/// # Notes
+///
+/// With the `serde` feature, `Zai` is (de)serialized as its [`name`](Self::name).
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Zai {
     atomic_number: u32,
@@ -59,16 +79,81 @@ impl Zai {
     /// - `mass_number >= 1000`
     /// - `isomeric_state_number >= 10`
     pub fn new(atomic_number: u32, mass_number: u32, isomeric_state_number: u32) -> Self {
-        assert!(atomic_number > 0);
-        assert!(atomic_number <= Element::MAX_ATOMIC_NUMBER);
-        assert!(mass_number >= atomic_number);
-        assert!(mass_number < 1000);
-        assert!(isomeric_state_number < 10);
-        Self {
+        match Self::checked_new(atomic_number, mass_number, isomeric_state_number) {
+            Ok(zai) => zai,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    /// Creates a new nuclide identifier (ZAI) from specified numbers, checking
+    /// their validity.
+    ///
+    /// Non panicking version of [`Zai::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::{Zai, ZaiError};
+    ///
+    /// assert_eq!(Zai::checked_new(92, 235, 0), Ok(Zai::new(92, 235, 0)));
+    /// assert_eq!(Zai::checked_new(92, 91, 0), Err(ZaiError::MassNumberBelowAtomicNumber));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// [`ZaiError`] is returned if
+    /// - `atomic_number` ∉ `[1, 118]`
+    /// - number of nucleons is less than number of protons (`mass_number < atomic_number`)
+    /// - `mass_number >= 1000`
+    /// - `isomeric_state_number >= 10`
+    pub fn checked_new(
+        atomic_number: u32,
+        mass_number: u32,
+        isomeric_state_number: u32,
+    ) -> Result<Self, ZaiError> {
+        if !(Element::MIN_ATOMIC_NUMBER..=Element::MAX_ATOMIC_NUMBER).contains(&atomic_number) {
+            return Err(ZaiError::AtomicNumberOutOfRange);
+        }
+        if mass_number < atomic_number {
+            return Err(ZaiError::MassNumberBelowAtomicNumber);
+        }
+        if mass_number >= 1000 {
+            return Err(ZaiError::MassNumberTooLarge);
+        }
+        if isomeric_state_number >= 10 {
+            return Err(ZaiError::IsomericStateNumberTooLarge);
+        }
+        Ok(Self {
             atomic_number,
             mass_number,
             isomeric_state_number,
-        }
+        })
+    }
+
+    /// Creates a new nuclide identifier (ZAI) from numbers of protons and neutrons.
+    ///
+    /// # Parameters
+    ///
+    /// - `protons`: number of protons `Z`
+    /// - `neutrons`: number of neutrons `N`
+    /// - `isomeric_state_number`: isomeric state number `I`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// // H3 -> Z = 1, N = 2, I = 0
+    /// let tritium = Zai::from_neutrons(1, 2, 0);
+    /// assert_eq!(tritium, Zai::new(1, 3, 0));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`new`](Self::new) with
+    /// `mass_number = protons + neutrons`.
+    pub fn from_neutrons(protons: u32, neutrons: u32, isomeric_state_number: u32) -> Self {
+        Self::new(protons, protons + neutrons, isomeric_state_number)
     }
 
     /// Creates a new nuclide identifier from nuclide's name.
@@ -116,23 +201,20 @@ impl Zai {
         let mut bytes = name.bytes().peekable();
         // Parse symbol.
         match bytes.next() {
-            Some(byte) if (b'A'..=b'Z').contains(&byte) => {
+            Some(byte) if byte.is_ascii_uppercase() => {
                 ptr += 1;
             }
             _ => return None,
         }
         match bytes.peek() {
-            Some(byte) if (b'a'..=b'z').contains(byte) => {
+            Some(byte) if byte.is_ascii_lowercase() => {
                 ptr += 1;
                 bytes.next();
             }
             _ => (),
         }
         // Convert symbol to atomic number.
-        let element = match Element::from_symbol(&name[..ptr]) {
-            Some(element) => element,
-            None => return None,
-        };
+        let element = Element::from_symbol(&name[..ptr])?;
         // Check atomic number.
         let atomic_number = element.atomic_number();
         if atomic_number == 0 || atomic_number > Element::MAX_ATOMIC_NUMBER {
@@ -148,7 +230,7 @@ impl Zai {
         }
         for _ in 0..2 {
             match bytes.peek() {
-                Some(byte) if (b'0'..=b'9').contains(byte) => {
+                Some(byte) if byte.is_ascii_digit() => {
                     ptr += 1;
                     bytes.next();
                 }
@@ -232,6 +314,183 @@ impl Zai {
         })
     }
 
+    /// Creates a new nuclide identifier from nuclide's id without validation.
+    ///
+    /// Intended for hot loops on ids known to be valid (e.g. obtained from
+    /// [`Zai::id`]), see [`Zai::from_id`] for the id format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let u235 = Zai::new(92, 235, 0);
+    /// assert_eq!(Zai::from_id_unchecked(u235.id()), u235);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// This function is safe: an invalid `id` does not cause undefined
+    /// behavior, but yields an invalid nuclide identifier for which other
+    /// methods may panic or return meaningless values. Validity is asserted
+    /// in debug builds only.
+    pub fn from_id_unchecked(id: u32) -> Self {
+        debug_assert!(Self::from_id(id).is_some(), "invalid nuclide id {id}");
+        Self {
+            atomic_number: id / 10000,
+            mass_number: id % 10000 / 10,
+            isomeric_state_number: id % 10,
+        }
+    }
+
+    /// Creates a new ground state nuclide identifier from nuclide's ZA number.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// ZA = Z × 1000 + A
+    /// ```
+    ///
+    /// with:
+    /// - `Z`: atomic number
+    /// - `A`: mass number
+    ///
+    /// # Returns
+    ///
+    /// - `Some(zai)` if `za` is a conformant nuclide's ZA number
+    /// - `None` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// // H1 -> Z = 1, A = 1, I = 0
+    /// assert_eq!(Zai::from_za(1001), Some(Zai::new(1, 1, 0)));
+    /// // U235 -> Z = 92, A = 235, I = 0
+    /// assert_eq!(Zai::from_za(92235), Some(Zai::new(92, 235, 0)));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// ZA numbers do not carry isomeric state: isomeric state number is always `0`.
+    pub fn from_za(za: u32) -> Option<Self> {
+        let atomic_number = za / 1000;
+        if atomic_number == 0 || atomic_number > Element::MAX_ATOMIC_NUMBER {
+            return None;
+        }
+        let mass_number = za % 1000;
+        if mass_number < atomic_number {
+            return None;
+        }
+        Some(Self {
+            atomic_number,
+            mass_number,
+            isomeric_state_number: 0,
+        })
+    }
+
+    /// Creates a new nuclide identifier from an ENDF ZA number and an explicit
+    /// isomeric state number `LISO`.
+    ///
+    /// # Format
+    ///
+    /// Two conventions are supported for `za`:
+    ///
+    /// ```text
+    /// ZA = Z × 1000 + A                  (isomeric state given by LISO)
+    /// ZA = Z × 1000 + A + 300 + 100 × I  (packed, see Zai::lfi_za)
+    /// ```
+    ///
+    /// with:
+    /// - `Z`: atomic number
+    /// - `A`: mass number
+    /// - `I`: isomeric state number
+    ///
+    /// ENDF-6 formatted files (e.g. `MF=1/MT=451` and `MF=8/MT=457` sections)
+    /// use the first convention: `ZA` is found in the HEAD record, and `LISO`
+    /// in the `L2` field of both the `MF=8/MT=457` HEAD record and the second
+    /// record of `MF=1/MT=451`. The packed convention is used by ACE libraries
+    /// processed from ENDF files (e.g. `95642` for Am242m1), in which case
+    /// `liso` should be `0`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(zai)` if `za` is a conformant ZA number, and `liso` is either
+    ///   `0` or consistent with a packed isomeric state
+    /// - `None` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// // U235 -> Z = 92, A = 235, I = 0
+    /// assert_eq!(Zai::from_endf_za(92235, 0), Some(Zai::new(92, 235, 0)));
+    /// // Am242m1 -> Z = 95, A = 242, I = 1
+    /// assert_eq!(Zai::from_endf_za(95242, 1), Some(Zai::new(95, 242, 1)));
+    /// assert_eq!(Zai::from_endf_za(95642, 0), Some(Zai::new(95, 242, 1)));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Packed ZA numbers are ambiguous (e.g. `A + 400` and `A - 100 + 500`),
+    /// the isomeric state yielding a mass number within
+    /// [`Element::mass_number_bounds`] is selected.
+    pub fn from_endf_za(za: u32, liso: u32) -> Option<Self> {
+        let Zai {
+            atomic_number,
+            mass_number,
+            ..
+        } = Self::from_za(za)?;
+        let (min, max) = Element::from_atomic_number(atomic_number)?.mass_number_bounds();
+        if mass_number <= max || mass_number < 400 {
+            return Self::checked_new(atomic_number, mass_number, liso).ok();
+        }
+        // Packed ZA number: A + 300 + 100 × I
+        let isomeric_state_number = (1..=(mass_number - 300) / 100)
+            .find(|state| (min..=max).contains(&(mass_number - 300 - 100 * state)))?;
+        if liso != 0 && liso != isomeric_state_number {
+            return None;
+        }
+        Self::checked_new(
+            atomic_number,
+            mass_number - 300 - 100 * isomeric_state_number,
+            isomeric_state_number,
+        )
+        .ok()
+    }
+
+    /// Returns an iterator over plausible ground state isotopes of `element`.
+    ///
+    /// Mass numbers range from `A = Z` to `A = 3 × Z + 20` (inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::{Element, Zai};
+    ///
+    /// let mut isotopes = Zai::iter_isotopes(Element::Hydrogen);
+    /// assert_eq!(isotopes.next(), Some(Zai::new(1, 1, 0)));
+    /// assert_eq!(isotopes.next(), Some(Zai::new(1, 2, 0)));
+    /// assert_eq!(isotopes.next(), Some(Zai::new(1, 3, 0)));
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// This iterator yields a generous superset of known isotopes, results
+    /// should be filtered against a data library (see
+    /// [`AtomicMassLibrary`](crate::data::mass::AtomicMassLibrary)) or
+    /// restricted to [`Element::mass_number_bounds`].
+    pub fn iter_isotopes(element: Element) -> impl Iterator<Item = Zai> {
+        let atomic_number = element.atomic_number();
+        (atomic_number..=3 * atomic_number + 20).map(move |mass_number| Self {
+            atomic_number,
+            mass_number,
+            isomeric_state_number: 0,
+        })
+    }
+
     /// Returns atomic number `Z`.
     ///
     /// # Examples
@@ -309,6 +568,99 @@ impl Zai {
         self.atomic_number * 10000 + self.mass_number * 10 + self.isomeric_state_number
     }
 
+    /// Returns nuclide `ZA` number.
+    ///
+    /// # Format
+    ///
+    /// Nuclide ZA number is given by:
+    ///
+    /// ```text
+    /// ZA = Z × 1000 + A
+    /// ```
+    ///
+    /// with:
+    /// - `Z`: atomic number
+    /// - `A`: mass number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let h1 = Zai::new(1, 1, 0);
+    /// assert_eq!(h1.za(), 1001);
+    ///
+    /// let u235 = Zai::new(92, 235, 0);
+    /// assert_eq!(u235.za(), 92235);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Isomeric state number is **not** included in ZA number.
+    pub fn za(&self) -> u32 {
+        self.atomic_number * 1000 + self.mass_number
+    }
+
+    /// Returns nuclide's `ZA` number as a string (e.g. `"92235"`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let u235 = Zai::new(92, 235, 0);
+    /// assert_eq!(u235.za_string(), "92235");
+    ///
+    /// let am242m1 = Zai::new(95, 242, 1);
+    /// assert_eq!(am242m1.za_string(), "95242");
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Isomeric state number is **not** included: see [`lfi_za`](Self::lfi_za)
+    /// for an identifier of metastable states.
+    pub fn za_string(&self) -> String {
+        self.za().to_string()
+    }
+
+    /// Returns nuclide's `ZA` number with isomeric state encoded in mass number.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// ZA = Z × 1000 + A                  if I = 0
+    /// ZA = Z × 1000 + A + 300 + 100 × I  if I > 0
+    /// ```
+    ///
+    /// with:
+    /// - `Z`: atomic number
+    /// - `A`: mass number
+    /// - `I`: isomeric state number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let u235 = Zai::new(92, 235, 0);
+    /// assert_eq!(u235.lfi_za(), 92235);
+    ///
+    /// let am242m1 = Zai::new(95, 242, 1);
+    /// assert_eq!(am242m1.lfi_za(), 95642);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// This is the metastable state convention used by MCNP/ACE libraries
+    /// (e.g. `95642` for Am242m1).
+    pub fn lfi_za(&self) -> u32 {
+        if self.is_ground_state() {
+            self.za()
+        } else {
+            self.za() + 300 + 100 * self.isomeric_state_number
+        }
+    }
+
     /// Returns number of protons `Z` (identical to *atomic number*).
     ///
     /// # Examples
@@ -342,6 +694,38 @@ impl Zai {
         self.mass_number() - self.atomic_number()
     }
 
+    /// Returns neutron number `N = A - Z` (identical to *number of neutrons*).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let tritium = Zai::new(1, 3, 0);
+    /// assert_eq!(tritium.neutron_number(), 2);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// [`neutrons`](Self::neutrons)
+    pub fn neutron_number(&self) -> u32 {
+        self.neutrons()
+    }
+
+    /// Returns neutron to proton ratio `N / Z`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let tritium = Zai::new(1, 3, 0);
+    /// assert_eq!(tritium.neutron_proton_ratio(), 2.);
+    /// ```
+    pub fn neutron_proton_ratio(&self) -> f64 {
+        self.neutrons() as f64 / self.protons() as f64
+    }
+
     /// Returns number of nucleons `A` (identical to *mass number*).
     ///
     /// # Examples
@@ -381,6 +765,38 @@ impl Zai {
         Element::from_atomic_number(self.atomic_number).unwrap()
     }
 
+    /// Returns nuclide identifier's chemical element symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let u235 = Zai::new(92, 235, 0);
+    /// assert_eq!(u235.element_symbol(), "U");
+    /// ```
+    pub fn element_symbol(&self) -> &'static str {
+        self.element().symbol()
+    }
+
+    /// Returns a copy of the nuclide identifier with specified isomeric state number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let tc99 = Zai::new(43, 99, 0);
+    /// assert_eq!(tc99.with_isomeric_state(1).name(), "Tc99m1");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `isomeric_state_number >= 10`
+    pub fn with_isomeric_state(&self, isomeric_state_number: u32) -> Self {
+        Self::new(self.atomic_number, self.mass_number, isomeric_state_number)
+    }
+
     /// Converts `ZAI` **to** `(Z, A, I)` tuple.
     ///
     /// # Examples
@@ -417,6 +833,49 @@ impl Zai {
         )
     }
 
+    /// Compares nuclides by element, then by mass number and isomeric state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let mut nuclides = vec![Zai::new(92, 235, 0), Zai::new(26, 56, 0), Zai::new(92, 234, 0)];
+    /// nuclides.sort_by(Zai::cmp_by_element);
+    /// assert_eq!(nuclides, [Zai::new(26, 56, 0), Zai::new(92, 234, 0), Zai::new(92, 235, 0)]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// This ordering is the same as `Zai`'s [`Ord`] implementation.
+    pub fn cmp_by_element(&self, other: &Zai) -> Ordering {
+        self.cmp(other)
+    }
+
+    /// Compares nuclides by mass number, then by atomic number and isomeric state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let mut nuclides = vec![Zai::new(7, 14, 0), Zai::new(6, 14, 0), Zai::new(6, 12, 0)];
+    /// nuclides.sort_by(Zai::cmp_by_mass);
+    /// assert_eq!(nuclides, [Zai::new(6, 12, 0), Zai::new(6, 14, 0), Zai::new(7, 14, 0)]);
+    /// ```
+    pub fn cmp_by_mass(&self, other: &Zai) -> Ordering {
+        (
+            self.mass_number,
+            self.atomic_number,
+            self.isomeric_state_number,
+        )
+            .cmp(&(
+                other.mass_number,
+                other.atomic_number,
+                other.isomeric_state_number,
+            ))
+    }
+
     /// Returns `true` if the nuclide identifier isomeric state `I` is `0`.
     ///
     /// # Examples
@@ -445,6 +904,103 @@ impl Zai {
         self.isomeric_state_number != 0
     }
 
+    /// Returns `true` if the nuclide is stable.
+    ///
+    /// A nuclide is considered stable if it is *observationally stable*: no
+    /// decay has ever been observed for it (251 nuclides).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let fe56 = Zai::new(26, 56, 0);
+    /// assert!(fe56.is_stable());
+    ///
+    /// let u235 = Zai::new(92, 235, 0);
+    /// assert!(!u235.is_stable());
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Only ground states are stable: metastable states (including the
+    /// long-lived Ta180m1) are never considered stable.
+    ///
+    /// # References
+    ///
+    /// [Wikipedia: Stable nuclide](https://en.wikipedia.org/wiki/Stable_nuclide)
+    pub fn is_stable(&self) -> bool {
+        STABLE_NUCLIDES.get().contains(self)
+    }
+
+    /// Returns nuclide's primary decay modes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::{DecayType, Zai};
+    ///
+    /// let u238 = Zai::new(92, 238, 0);
+    /// let modes = u238.decay_modes().unwrap();
+    /// assert_eq!(modes.len(), 1);
+    /// assert_eq!(modes[0].decay_type(), DecayType::Alpha);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(modes)` if nuclide's decay data is available
+    /// - `None` for stable nuclides and nuclides without available decay data
+    ///
+    /// # Notes
+    ///
+    /// Decay data is available for a limited set of common radionuclides
+    /// (activation products, fission products and actinides) from NUBASE2020
+    /// evaluation. Decay modes with a branching ratio lower than 0.01 % are omitted.
+    ///
+    /// # References
+    ///
+    /// Kondev, F.G., Wang, M., Huang, W.J., Naimi, S. and Audi, G.
+    /// *The NUBASE2020 evaluation of nuclear physics properties*.
+    /// Chinese Physics C, vol. 45, no. 3, 2021, 030001.
+    /// <https://doi.org/10.1088/1674-1137/abddae>
+    pub fn decay_modes(&self) -> Option<Vec<DecayMode>> {
+        decay::decay_modes(*self)
+    }
+
+    /// Returns nuclide's half-life in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// let co60 = Zai::new(27, 60, 0);
+    /// let half_life = co60.half_life().unwrap();
+    /// assert!((half_life / 1.663e8 - 1.0).abs() < 1e-3);
+    /// assert_eq!(Zai::new(26, 56, 0).half_life(), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// - `Some(half_life)` if nuclide's decay data is available
+    /// - `None` for stable nuclides and nuclides without available decay data
+    ///
+    /// # Notes
+    ///
+    /// Half-lives are available for the same nuclides as
+    /// [`decay_modes`](Self::decay_modes), from NUBASE2020 evaluation. Half-lives
+    /// given in years are converted with 1 y = 365.2422 d.
+    ///
+    /// # References
+    ///
+    /// Kondev, F.G., Wang, M., Huang, W.J., Naimi, S. and Audi, G.
+    /// *The NUBASE2020 evaluation of nuclear physics properties*.
+    /// Chinese Physics C, vol. 45, no. 3, 2021, 030001.
+    /// <https://doi.org/10.1088/1674-1137/abddae>
+    pub fn half_life(&self) -> Option<f64> {
+        decay::half_life(*self)
+    }
+
     /// Returns nuclide's name identified by this `ZAI` identifier.
     ///
     /// # Examples
@@ -469,12 +1025,212 @@ impl Zai {
             format!("{}{}m{}", symbol, mass, isomer)
         }
     }
+
+    /// Returns nuclide's LaTeX notation.
+    ///
+    /// # Format
+    ///
+    /// Mass number (followed by `m<I>` for metastable states) is written as a
+    /// superscript preceding the upright element symbol:
+    ///
+    /// ```text
+    /// ground state:     ^{<A>}\mathrm{<symbol>}
+    /// metastable state: ^{<A>m<I>}\mathrm{<symbol>}
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// assert_eq!(Zai::new(92, 235, 0).to_latex(), r"^{235}\mathrm{U}");
+    /// assert_eq!(Zai::new(95, 242, 1).to_latex(), r"^{242m1}\mathrm{Am}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let symbol = self.element_symbol();
+        let mass = self.mass_number;
+        if self.is_ground_state() {
+            format!(r"^{{{mass}}}\mathrm{{{symbol}}}")
+        } else {
+            let isomer = self.isomeric_state_number;
+            format!(r"^{{{mass}m{isomer}}}\mathrm{{{symbol}}}")
+        }
+    }
+
+    /// Returns nuclide's notation with Unicode superscript mass number.
+    ///
+    /// # Format
+    ///
+    /// Mass number digits are mapped to Unicode superscript digits and
+    /// prefixed to the element symbol; metastable states are suffixed with
+    /// `m<I>` in normal characters (e.g. `²⁴²Amm1`). Digit mapping:
+    ///
+    /// | Digit | Superscript | Code point |
+    /// |-------|-------------|------------|
+    /// | `0`   | `⁰`         | U+2070     |
+    /// | `1`   | `¹`         | U+00B9     |
+    /// | `2`   | `²`         | U+00B2     |
+    /// | `3`   | `³`         | U+00B3     |
+    /// | `4`   | `⁴`         | U+2074     |
+    /// | `5`   | `⁵`         | U+2075     |
+    /// | `6`   | `⁶`         | U+2076     |
+    /// | `7`   | `⁷`         | U+2077     |
+    /// | `8`   | `⁸`         | U+2078     |
+    /// | `9`   | `⁹`         | U+2079     |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    ///
+    /// assert_eq!(Zai::new(92, 235, 0).to_unicode(), "²³⁵U");
+    /// assert_eq!(Zai::new(95, 242, 1).to_unicode(), "²⁴²Amm1");
+    /// ```
+    pub fn to_unicode(&self) -> String {
+        const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+        let mut unicode: String = self
+            .mass_number
+            .to_string()
+            .bytes()
+            .map(|digit| SUPERSCRIPTS[(digit - b'0') as usize])
+            .collect();
+        unicode.push_str(self.element_symbol());
+        if self.is_metastable_state() {
+            unicode.push_str(&format!("m{}", self.isomeric_state_number));
+        }
+        unicode
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Zai {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.name())
+    }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Zai {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ZaiVisitor;
+
+        impl serde::de::Visitor<'_> for ZaiVisitor {
+            type Value = Zai;
+
+            fn expecting(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(fmt, "a nuclide name")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Zai, E> {
+                Zai::from_name(name)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(name), &self))
+            }
+        }
+
+        deserializer.deserialize_str(ZaiVisitor)
+    }
+}
+
+/// Error returned when creating a [`Zai`] from invalid numbers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ZaiError {
+    /// Atomic number out of periodic table range `[1, 118]`.
+    AtomicNumberOutOfRange,
+    /// Mass number less than atomic number.
+    MassNumberBelowAtomicNumber,
+    /// Mass number greater than 999.
+    MassNumberTooLarge,
+    /// Isomeric state number greater than 9.
+    IsomericStateNumberTooLarge,
+}
+
+impl Display for ZaiError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZaiError::AtomicNumberOutOfRange => write!(fmt, "atomic number out of range"),
+            ZaiError::MassNumberBelowAtomicNumber => {
+                write!(fmt, "mass number less than atomic number")
+            }
+            ZaiError::MassNumberTooLarge => write!(fmt, "mass number too large"),
+            ZaiError::IsomericStateNumberTooLarge => {
+                write!(fmt, "isomeric state number too large")
+            }
+        }
+    }
+}
+
+impl Error for ZaiError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn element_symbol() {
+        assert_eq!(Zai::new(1, 2, 0).element_symbol(), "H");
+        assert_eq!(Zai::new(95, 242, 1).element_symbol(), "Am");
+        assert_eq!(Zai::new(118, 294, 0).element_symbol(), "Og");
+    }
+
+    #[test]
+    fn with_isomeric_state() {
+        let tc99 = Zai::new(43, 99, 0);
+        let tc99m1 = tc99.with_isomeric_state(1);
+        assert_eq!(tc99m1.name(), "Tc99m1");
+        assert_eq!(tc99m1.with_isomeric_state(0), tc99);
+    }
+
+    #[test]
+    #[should_panic]
+    fn with_isomeric_state_invalid() {
+        Zai::new(43, 99, 0).with_isomeric_state(10);
+    }
+
+    #[test]
+    fn checked_new() {
+        assert_eq!(Zai::checked_new(1, 1, 0), Ok(Zai::new(1, 1, 0)));
+        assert_eq!(Zai::checked_new(95, 242, 1), Ok(Zai::new(95, 242, 1)));
+        assert_eq!(
+            Zai::checked_new(0, 1, 0),
+            Err(ZaiError::AtomicNumberOutOfRange)
+        );
+        assert_eq!(
+            Zai::checked_new(119, 300, 0),
+            Err(ZaiError::AtomicNumberOutOfRange)
+        );
+        assert_eq!(
+            Zai::checked_new(92, 91, 0),
+            Err(ZaiError::MassNumberBelowAtomicNumber)
+        );
+        assert_eq!(
+            Zai::checked_new(92, 1000, 0),
+            Err(ZaiError::MassNumberTooLarge)
+        );
+        assert_eq!(
+            Zai::checked_new(92, 235, 10),
+            Err(ZaiError::IsomericStateNumberTooLarge)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "mass number less than atomic number")]
+    fn new_invalid() {
+        Zai::new(92, 91, 0);
+    }
+
+    #[test]
+    fn from_id_unchecked() {
+        for zai in [
+            Zai::new(1, 1, 0),
+            Zai::new(26, 56, 0),
+            Zai::new(92, 235, 0),
+            Zai::new(95, 242, 1),
+            Zai::new(118, 294, 0),
+        ] {
+            assert_eq!(Zai::from_id_unchecked(zai.id()), zai);
+        }
+    }
+
     #[test]
     #[should_panic]
     fn new_invalid_atomic_number_min() {
@@ -499,6 +1255,29 @@ mod tests {
         Zai::new(2, 1, 0);
     }
 
+    #[test]
+    fn from_neutrons() {
+        let tritium = Zai::from_neutrons(1, 2, 0);
+        assert_eq!(tritium, Zai::new(1, 3, 0));
+        assert_eq!(tritium.neutrons(), 2);
+    }
+
+    #[test]
+    fn neutron_proton_ratio() {
+        let u238 = Zai::new(92, 238, 0);
+        assert_eq!(u238.neutron_number(), 146);
+        assert!((u238.neutron_proton_ratio() - 1.587).abs() < 1e-3);
+        let fe56 = Zai::new(26, 56, 0);
+        assert!((fe56.neutron_proton_ratio() - 1.154).abs() < 1e-3);
+        assert_eq!(Zai::new(1, 1, 0).neutron_proton_ratio(), 0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_neutrons_invalid_mass_number() {
+        Zai::from_neutrons(92, 908, 0);
+    }
+
     #[test]
     fn from_name_invalid() {
         // invalid symbol
@@ -538,6 +1317,47 @@ mod tests {
         assert!(Zai::from_id(12310001).is_none()); // A >= 1000
     }
 
+    #[test]
+    fn from_za() {
+        assert_eq!(Zai::from_za(92235), Zai::from_name("U235"));
+        assert_eq!(Zai::from_za(92235).unwrap().za(), 92235);
+    }
+
+    #[test]
+    fn from_za_invalid() {
+        assert!(Zai::from_za(1000).is_none()); // A = 0
+        assert!(Zai::from_za(119000).is_none()); // Z > 118
+        assert!(Zai::from_za(119300).is_none()); // Z > 118
+        assert!(Zai::from_za(999).is_none()); // Z = 0
+        assert!(Zai::from_za(2001).is_none()); // A < Z
+    }
+
+    #[test]
+    fn from_endf_za() {
+        let u235 = Zai::new(92, 235, 0);
+        let am242m1 = Zai::new(95, 242, 1);
+        assert_eq!(Zai::from_endf_za(92235, 0), Some(u235));
+        assert_eq!(Zai::from_endf_za(95242, 1), Some(am242m1));
+        assert_eq!(Zai::from_endf_za(95642, 0), Some(am242m1));
+        assert_eq!(Zai::from_endf_za(95642, 1), Some(am242m1));
+        assert_eq!(Zai::from_endf_za(am242m1.lfi_za(), 0), Some(am242m1));
+        assert!(Zai::from_endf_za(95642, 2).is_none());
+        assert!(Zai::from_endf_za(92235, 10).is_none());
+        assert!(Zai::from_endf_za(119235, 0).is_none());
+    }
+
+    #[test]
+    fn iter_isotopes() {
+        let names: Vec<String> = Zai::iter_isotopes(Element::Hydrogen)
+            .take(3)
+            .map(|zai| zai.name())
+            .collect();
+        assert_eq!(names, ["H1", "H2", "H3"]);
+        assert_eq!(Zai::iter_isotopes(Element::Hydrogen).count(), 23);
+        let last = Zai::iter_isotopes(Element::Oganesson).last().unwrap();
+        assert_eq!(last, Zai::new(118, 374, 0));
+    }
+
     #[test]
     fn name() {
         assert_eq!(Zai::new(1, 1, 0).name(), "H1");
@@ -546,4 +1366,119 @@ mod tests {
         assert_eq!(Zai::new(27, 58, 1).name(), "Co58m1");
         assert_eq!(Zai::new(72, 178, 2).name(), "Hf178m2");
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let u235 = Zai::new(92, 235, 0);
+        let json = serde_json::to_string(&u235).unwrap();
+        assert_eq!(json, "\"U235\"");
+        assert_eq!(serde_json::from_str::<Zai>(&json).unwrap(), u235);
+
+        let am242m1 = Zai::new(95, 242, 1);
+        let json = serde_json::to_string(&am242m1).unwrap();
+        assert_eq!(json, "\"Am242m1\"");
+        assert_eq!(serde_json::from_str::<Zai>(&json).unwrap(), am242m1);
+
+        assert!(serde_json::from_str::<Zai>("\"Xx1\"").is_err());
+        assert!(serde_json::from_str::<Zai>("922350").is_err());
+    }
+
+    #[test]
+    fn is_stable() {
+        assert!(Zai::new(1, 1, 0).is_stable());
+        assert!(Zai::new(26, 56, 0).is_stable());
+        assert!(Zai::new(82, 208, 0).is_stable());
+        assert!(!Zai::new(1, 3, 0).is_stable());
+        assert!(!Zai::new(19, 40, 0).is_stable());
+        assert!(!Zai::new(83, 209, 0).is_stable());
+        assert!(!Zai::new(92, 235, 0).is_stable());
+        assert!(!Zai::new(43, 99, 1).is_stable());
+        assert!(!Zai::new(73, 180, 1).is_stable());
+        let stable = Element::iter()
+            .flat_map(Zai::iter_isotopes)
+            .filter(Zai::is_stable)
+            .count();
+        assert_eq!(stable, 251);
+    }
+
+    #[test]
+    fn za_string() {
+        assert_eq!(Zai::new(1, 1, 0).za_string(), "1001");
+        assert_eq!(Zai::new(92, 235, 0).za_string(), "92235");
+        assert_eq!(Zai::new(95, 242, 1).za_string(), "95242");
+        assert_eq!(Zai::new(92, 235, 0).lfi_za(), 92235);
+        assert_eq!(Zai::new(95, 242, 1).lfi_za(), 95642);
+        assert_eq!(Zai::new(52, 127, 2).lfi_za(), 52627);
+    }
+
+    #[test]
+    fn cmp() {
+        let h3 = Zai::new(1, 3, 0);
+        let he3 = Zai::new(2, 3, 0);
+        let he4 = Zai::new(2, 4, 0);
+        let am242 = Zai::new(95, 242, 0);
+        let am242m1 = Zai::new(95, 242, 1);
+        let pu242 = Zai::new(94, 242, 0);
+        let mut nuclides = vec![am242m1, he4, pu242, he3, am242, h3];
+        nuclides.sort_by(Zai::cmp_by_element);
+        assert_eq!(nuclides, [h3, he3, he4, pu242, am242, am242m1]);
+        let mut nuclides = vec![he4, Zai::new(1, 5, 0), he3];
+        nuclides.sort_by(Zai::cmp_by_mass);
+        assert_eq!(nuclides, [he3, he4, Zai::new(1, 5, 0)]);
+        nuclides.sort_by(Zai::cmp_by_element);
+        assert_eq!(nuclides, [Zai::new(1, 5, 0), he3, he4]);
+    }
+
+    #[test]
+    fn decay_modes() {
+        use crate::core::DecayType;
+
+        let co60 = Zai::new(27, 60, 0).decay_modes().unwrap();
+        assert_eq!(co60.len(), 1);
+        assert_eq!(co60[0].decay_type(), DecayType::BetaMinus);
+        assert_eq!(co60[0].branching_ratio(), 1.0);
+        let u238 = Zai::new(92, 238, 0).decay_modes().unwrap();
+        assert_eq!(u238.len(), 1);
+        assert_eq!(u238[0].decay_type(), DecayType::Alpha);
+        let k40 = Zai::new(19, 40, 0).decay_modes().unwrap();
+        assert_eq!(k40[0].decay_type(), DecayType::BetaMinus);
+        assert_eq!(k40[1].decay_type(), DecayType::BetaPlus);
+        let am242m1 = Zai::new(95, 242, 1).decay_modes().unwrap();
+        assert_eq!(am242m1[0].decay_type(), DecayType::IsomericTransition);
+        assert!(Zai::new(26, 56, 0).decay_modes().is_none());
+        assert!(Zai::new(118, 294, 0).decay_modes().is_none());
+    }
+
+    #[test]
+    fn to_latex() {
+        assert_eq!(Zai::new(92, 235, 0).to_latex(), r"^{235}\mathrm{U}");
+        assert_eq!(Zai::new(95, 242, 1).to_latex(), r"^{242m1}\mathrm{Am}");
+        assert_eq!(Zai::new(1, 2, 0).to_latex(), r"^{2}\mathrm{H}");
+    }
+
+    #[test]
+    fn to_unicode() {
+        assert_eq!(Zai::new(92, 235, 0).to_unicode(), "²³⁵U");
+        assert_eq!(Zai::new(1, 2, 0).to_unicode(), "²H");
+        assert_eq!(Zai::new(95, 242, 1).to_unicode(), "²⁴²Amm1");
+        assert_eq!(Zai::new(27, 60, 0).to_unicode(), "⁶⁰Co");
+        assert_eq!(Zai::new(1, 1, 0).to_unicode(), "¹H");
+        assert_eq!(Zai::new(20, 40, 0).to_unicode(), "⁴⁰Ca");
+        assert_eq!(Zai::new(36, 78, 0).to_unicode(), "⁷⁸Kr");
+        assert_eq!(Zai::new(39, 89, 0).to_unicode(), "⁸⁹Y");
+    }
+
+    #[test]
+    fn half_life() {
+        let co60 = Zai::new(27, 60, 0).half_life().unwrap();
+        assert!((co60 / 1.663e8 - 1.0).abs() < 1e-3);
+        let u238 = Zai::new(92, 238, 0).half_life().unwrap();
+        assert!((u238 / 1.41e17 - 1.0).abs() < 1e-3);
+        assert!(
+            Zai::new(95, 242, 1).half_life().unwrap() > Zai::new(95, 242, 0).half_life().unwrap()
+        );
+        assert_eq!(Zai::new(26, 56, 0).half_life(), None);
+        assert_eq!(Zai::new(118, 294, 0).half_life(), None);
+    }
 }
@@ -5,11 +5,14 @@
 //! Conlin, J. L., & Romano, P. (2019). *A compact ENDF (ACE) format specification*
 //! (No. LA-UR-19-29016). Los Alamos National Lab.(LANL), Los Alamos, NM (United States).
 
+mod arrays;
+pub use arrays::{Jxs, Nxs};
+
 mod error;
 pub use error::AceError;
 
 mod table;
-pub use table::Table;
+pub use table::{ParseTableTypeError, Table, TableType, BOLTZMANN_CONSTANT};
 
 mod parse;
 pub use parse::parse_ace_table;
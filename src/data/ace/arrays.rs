@@ -0,0 +1,241 @@
+/// ACE table NXS array.
+///
+/// NXS array holds 16 integers describing table's layout. Named accessors follow
+/// continuous-energy neutron tables conventions: meaning of the slots may differ
+/// for other table types (see [`TableType`](super::TableType)).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Nxs(pub(crate) [usize; 16]);
+
+impl Nxs {
+    /// Returns NXS array as a slice.
+    pub fn as_slice(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Returns XSS array length, NXS(1).
+    pub fn xss_length(&self) -> usize {
+        self.0[0]
+    }
+
+    /// Returns `ZA` number of the target nuclide, NXS(2).
+    ///
+    /// `ZA = Z × 1000 + A`.
+    pub fn za(&self) -> usize {
+        self.0[1]
+    }
+
+    /// Returns energy grid length, NXS(3) (`NES`).
+    pub fn energy_grid_length(&self) -> usize {
+        self.0[2]
+    }
+
+    /// Returns number of reactions, NXS(4) (`NTR`).
+    ///
+    /// Elastic scattering is excluded.
+    pub fn reaction_count(&self) -> usize {
+        self.0[3]
+    }
+
+    /// Returns number of reactions with secondary neutrons, NXS(5) (`NR`).
+    ///
+    /// Elastic scattering is excluded.
+    pub fn neutron_reaction_count(&self) -> usize {
+        self.0[4]
+    }
+
+    /// Returns number of photon production reactions, NXS(6) (`NTRP`).
+    pub fn photon_reaction_count(&self) -> usize {
+        self.0[5]
+    }
+
+    /// Returns number of secondary particle types, NXS(7) (`NTYPE`).
+    pub fn particle_type_count(&self) -> usize {
+        self.0[6]
+    }
+
+    /// Returns number of delayed neutron precursor families, NXS(8) (`NPCR`).
+    pub fn precursor_family_count(&self) -> usize {
+        self.0[7]
+    }
+
+    /// Returns excited state of the target nuclide, NXS(9) (`S`).
+    pub fn excited_state(&self) -> usize {
+        self.0[8]
+    }
+
+    /// Returns atomic number of the target nuclide, NXS(10) (`Z`).
+    pub fn atomic_number(&self) -> usize {
+        self.0[9]
+    }
+
+    /// Returns mass number of the target nuclide, NXS(11) (`A`).
+    pub fn mass_number(&self) -> usize {
+        self.0[10]
+    }
+}
+
+/// ACE table JXS array.
+///
+/// JXS array holds 32 integers locating data blocks inside the XSS array: each
+/// location is a 1-based XSS index, `0` when the block is absent. Named
+/// accessors follow continuous-energy neutron tables conventions: meaning of
+/// the slots may differ for other table types (see [`TableType`](super::TableType)).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Jxs(pub(crate) [usize; 32]);
+
+impl Jxs {
+    /// Returns JXS array as a slice.
+    pub fn as_slice(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Returns location of the energy table (energy grid and main cross sections) block, JXS(1) (`ESZ`).
+    pub fn energy_table(&self) -> usize {
+        self.0[0]
+    }
+
+    /// Returns location of the fission ν data block, JXS(2) (`NU`).
+    pub fn fission_nu(&self) -> usize {
+        self.0[1]
+    }
+
+    /// Returns location of the reaction MT numbers block, JXS(3) (`MTR`).
+    pub fn reaction_mt(&self) -> usize {
+        self.0[2]
+    }
+
+    /// Returns location of the reaction Q-values block, JXS(4) (`LQR`).
+    pub fn q_values(&self) -> usize {
+        self.0[3]
+    }
+
+    /// Returns location of the reaction neutron yields and reference frames block, JXS(5) (`TYR`).
+    pub fn neutron_yields(&self) -> usize {
+        self.0[4]
+    }
+
+    /// Returns location of the reaction cross section locators block, JXS(6) (`LSIG`).
+    pub fn cross_section_locators(&self) -> usize {
+        self.0[5]
+    }
+
+    /// Returns location of the reaction cross sections block, JXS(7) (`SIG`).
+    pub fn cross_sections(&self) -> usize {
+        self.0[6]
+    }
+
+    /// Returns location of the angular distribution locators block, JXS(8) (`LAND`).
+    pub fn angular_distribution_locators(&self) -> usize {
+        self.0[7]
+    }
+
+    /// Returns location of the angular distributions block, JXS(9) (`AND`).
+    pub fn angular_distributions(&self) -> usize {
+        self.0[8]
+    }
+
+    /// Returns location of the energy distribution locators block, JXS(10) (`LDLW`).
+    pub fn energy_distribution_locators(&self) -> usize {
+        self.0[9]
+    }
+
+    /// Returns location of the energy distributions block, JXS(11) (`DLW`).
+    pub fn energy_distributions(&self) -> usize {
+        self.0[10]
+    }
+
+    /// Returns location of the photon production data block, JXS(12) (`GPD`).
+    pub fn photon_production(&self) -> usize {
+        self.0[11]
+    }
+
+    /// Returns location of the photon production reaction MT numbers block, JXS(13) (`MTRP`).
+    pub fn photon_reaction_mt(&self) -> usize {
+        self.0[12]
+    }
+
+    /// Returns location of the photon production cross section locators block, JXS(14) (`LSIGP`).
+    pub fn photon_cross_section_locators(&self) -> usize {
+        self.0[13]
+    }
+
+    /// Returns location of the photon production cross sections block, JXS(15) (`SIGP`).
+    pub fn photon_cross_sections(&self) -> usize {
+        self.0[14]
+    }
+
+    /// Returns location of the photon angular distribution locators block, JXS(16) (`LANDP`).
+    pub fn photon_angular_distribution_locators(&self) -> usize {
+        self.0[15]
+    }
+
+    /// Returns location of the photon angular distributions block, JXS(17) (`ANDP`).
+    pub fn photon_angular_distributions(&self) -> usize {
+        self.0[16]
+    }
+
+    /// Returns location of the photon energy distribution locators block, JXS(18) (`LDLWP`).
+    pub fn photon_energy_distribution_locators(&self) -> usize {
+        self.0[17]
+    }
+
+    /// Returns location of the photon energy distributions block, JXS(19) (`DLWP`).
+    pub fn photon_energy_distributions(&self) -> usize {
+        self.0[18]
+    }
+
+    /// Returns location of the photon production yield multipliers block, JXS(20) (`YP`).
+    pub fn photon_yields(&self) -> usize {
+        self.0[19]
+    }
+
+    /// Returns location of the total fission cross section block, JXS(21) (`FIS`).
+    pub fn fission(&self) -> usize {
+        self.0[20]
+    }
+
+    /// Returns location of the last word of the table, JXS(22) (`END`).
+    pub fn end(&self) -> usize {
+        self.0[21]
+    }
+
+    /// Returns location of the unresolved resonance probability tables block, JXS(23) (`LUNR`).
+    pub fn probability_tables(&self) -> usize {
+        self.0[22]
+    }
+
+    /// Returns location of the delayed ν data block, JXS(24) (`DNU`).
+    pub fn delayed_nu(&self) -> usize {
+        self.0[23]
+    }
+
+    /// Returns location of the delayed neutron precursor data block, JXS(25) (`BDD`).
+    pub fn precursor_data(&self) -> usize {
+        self.0[24]
+    }
+
+    /// Returns location of the delayed neutron energy distribution locators block, JXS(26) (`DNEDL`).
+    pub fn delayed_energy_distribution_locators(&self) -> usize {
+        self.0[25]
+    }
+
+    /// Returns location of the delayed neutron energy distributions block, JXS(27) (`DNED`).
+    pub fn delayed_energy_distributions(&self) -> usize {
+        self.0[26]
+    }
+
+    /// Returns location of the secondary particle types block, JXS(30) (`PTYPE`).
+    pub fn particle_types(&self) -> usize {
+        self.0[29]
+    }
+
+    /// Returns location of the particle production reaction counts block, JXS(31) (`NTRO`).
+    pub fn particle_production_counts(&self) -> usize {
+        self.0[30]
+    }
+
+    /// Returns location of the particle production data locators block, JXS(32) (`NEXT`).
+    pub fn particle_production_locators(&self) -> usize {
+        self.0[31]
+    }
+}
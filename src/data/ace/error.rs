@@ -4,6 +4,7 @@ use std::io::Error as IOError;
 
 /// The error type for [`ace`](crate::data::ace) module.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum AceError {
     /// Invalid data.
     Data,
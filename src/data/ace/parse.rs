@@ -1,7 +1,7 @@
 use std::io::Read;
 use std::str::Lines;
 
-use super::{AceError, Table};
+use super::{AceError, Jxs, Nxs, Table};
 
 /// Parse ACE table.
 ///
@@ -20,11 +20,22 @@ use super::{AceError, Table};
 /// # Ok(())
 /// # }
 /// ```
+///
+/// # Errors
+///
+/// [`AceError`] is returned if:
+/// - I/O error occurs
+/// - invalid table format
+/// - XSS array holds less values than its declared length NXS(1)
+///
+/// [`AceError::Data`] is returned for well-formatted but invalid tables:
+/// - ZAID is not made of a name and a suffix separated by a `.` (e.g. `92235.80c`)
+/// - XSS array length NXS(1) is zero
 pub fn parse_ace_table<R: Read>(mut table: R) -> Result<Table, AceError> {
     let mut ace = String::new();
     table.read_to_string(&mut ace)?;
     let Some(line) = ace.lines().next() else {
-        return Err(AceError::EndOfFile)
+        return Err(AceError::EndOfFile);
     };
     if line.starts_with("2.") {
         parse_table_version2(ace)
@@ -36,56 +47,64 @@ pub fn parse_ace_table<R: Read>(mut table: R) -> Result<Table, AceError> {
 fn parse_table_version1(ace: String) -> Result<Table, AceError> {
     let mut iter = ace.lines();
     let Some(line) = iter.next() else {
-        return Err(AceError::EndOfFile)
+        return Err(AceError::EndOfFile);
     };
-    let id = line[..10].trim().to_owned();
-    let Ok(atomic_weight_ratio) = line[10..22].trim().parse() else {
-        return Err(AceError::Format)
+    let Some(id) = line.get(..10).map(|field| field.trim().to_owned()) else {
+        return Err(AceError::Format);
     };
-    let Ok(temperature) = line[22..34].trim().parse() else {
-        return Err(AceError::Format)
+    let Some(Ok(atomic_weight_ratio)) = line.get(10..22).map(|field| field.trim().parse()) else {
+        return Err(AceError::Format);
+    };
+    let Some(Ok(temperature)) = line.get(22..34).map(|field| field.trim().parse()) else {
+        return Err(AceError::Format);
     };
     iter.next();
-    let izaw = parse_izaw_array(&mut iter)?;
-    let nxs = parse_nxs_array(&mut iter)?;
-    let jxs = parse_jxs_array(&mut iter)?;
-    let xss = parse_xss_array(&mut iter, nxs[0])?;
-    Ok(Table {
-        id,
-        atomic_weight_ratio,
-        temperature,
-        izaw,
-        nxs,
-        jxs,
-        xss,
-    })
+    parse_table_arrays(id, atomic_weight_ratio, temperature, &mut iter)
 }
 
 fn parse_table_version2(ace: String) -> Result<Table, AceError> {
     let mut iter = ace.lines();
     let Some(line) = iter.next() else {
-        return Err(AceError::EndOfFile)
+        return Err(AceError::EndOfFile);
+    };
+    let Some(id) = line.get(11..35).map(|field| field.trim().to_owned()) else {
+        return Err(AceError::Format);
     };
-    let id = line[11..35].trim().to_owned();
     let Some(line) = iter.next() else {
-        return Err(AceError::EndOfFile)
+        return Err(AceError::EndOfFile);
     };
-    let Ok(atomic_weight_ratio) = line[..12].trim().parse() else {
-        return Err(AceError::Format)
+    let Some(Ok(atomic_weight_ratio)) = line.get(..12).map(|field| field.trim().parse()) else {
+        return Err(AceError::Format);
     };
-    let Ok(temperature) = line[13..25].trim().parse() else {
-        return Err(AceError::Format)
+    let Some(Ok(temperature)) = line.get(13..25).map(|field| field.trim().parse()) else {
+        return Err(AceError::Format);
     };
-    let Ok(comment) = line[37..].trim().parse() else {
-        return Err(AceError::Format)
+    let Some(Ok(comment)) = line.get(37..).map(|field| field.trim().parse::<usize>()) else {
+        return Err(AceError::Format);
     };
     for _ in 0..comment {
         iter.next();
     }
-    let izaw = parse_izaw_array(&mut iter)?;
-    let nxs = parse_nxs_array(&mut iter)?;
-    let jxs = parse_jxs_array(&mut iter)?;
-    let xss = parse_xss_array(&mut iter, nxs[0])?;
+    parse_table_arrays(id, atomic_weight_ratio, temperature, &mut iter)
+}
+
+// Parse arrays common to all table versions (IZAW, NXS, JXS and XSS).
+fn parse_table_arrays(
+    id: String,
+    atomic_weight_ratio: f64,
+    temperature: f64,
+    lines: &mut Lines,
+) -> Result<Table, AceError> {
+    if !is_valid_zaid(&id) {
+        return Err(AceError::Data);
+    }
+    let izaw = parse_izaw_array(lines)?;
+    let nxs = Nxs(parse_integer_array(lines)?);
+    let jxs = Jxs(parse_integer_array(lines)?);
+    if nxs.xss_length() == 0 {
+        return Err(AceError::Data);
+    }
+    let xss = parse_xss_array(lines, nxs.xss_length())?;
     Ok(Table {
         id,
         atomic_weight_ratio,
@@ -97,22 +116,30 @@ fn parse_table_version2(ace: String) -> Result<Table, AceError> {
     })
 }
 
+// Checks ZAID is made of non-empty name and suffix separated by a `.`.
+fn is_valid_zaid(zaid: &str) -> bool {
+    match zaid.rsplit_once('.') {
+        Some((name, suffix)) => !name.is_empty() && !suffix.is_empty(),
+        None => false,
+    }
+}
+
 fn parse_izaw_array(lines: &mut Lines) -> Result<Vec<(u32, f64)>, AceError> {
     let mut izaw = Vec::with_capacity(16);
     for _ in 0..4 {
         let Some(line) = lines.next() else {
-            return Err(AceError::EndOfFile)
+            return Err(AceError::EndOfFile);
         };
         for i in 0..4 {
             let mut start = i * 18;
             let mut stop = start + 7;
-            let Ok(iz) = line[start..stop].trim().parse() else {
-                return Err(AceError::Format)
+            let Some(Ok(iz)) = line.get(start..stop).map(|field| field.trim().parse()) else {
+                return Err(AceError::Format);
             };
             start = stop;
             stop = start + 11;
-            let Ok(aw) = line[start..stop].trim().parse() else {
-                return Err(AceError::Format)
+            let Some(Ok(aw)) = line.get(start..stop).map(|field| field.trim().parse()) else {
+                return Err(AceError::Format);
             };
             izaw.push((iz, aw));
         }
@@ -120,53 +147,47 @@ fn parse_izaw_array(lines: &mut Lines) -> Result<Vec<(u32, f64)>, AceError> {
     Ok(izaw)
 }
 
-fn parse_nxs_array(lines: &mut Lines) -> Result<Vec<usize>, AceError> {
-    let mut nxs = Vec::with_capacity(16);
-    for _ in 0..2 {
-        let Some(line) = lines.next() else {
-            return Err(AceError::EndOfFile)
-        };
-        for i in 0..8 {
-            let start = i * 9;
-            let stop = i * 9 + 9;
-            let Ok(integer) = line[start..stop].trim().parse() else {
-                return Err(AceError::Format)
-            };
-            nxs.push(integer);
-        }
-    }
-    Ok(nxs)
-}
-
-fn parse_jxs_array(lines: &mut Lines) -> Result<Vec<usize>, AceError> {
-    let mut nxs = Vec::with_capacity(16);
-    for _ in 0..4 {
+// Parse NXS (16 values, 2 lines) or JXS (32 values, 4 lines) integer array,
+// 8 integers per line.
+fn parse_integer_array<const N: usize>(lines: &mut Lines) -> Result<[usize; N], AceError> {
+    let mut array = [0; N];
+    for chunk in array.chunks_mut(8) {
         let Some(line) = lines.next() else {
-            return Err(AceError::EndOfFile)
+            return Err(AceError::EndOfFile);
         };
-        for i in 0..8 {
+        for (i, integer) in chunk.iter_mut().enumerate() {
             let start = i * 9;
             let stop = i * 9 + 9;
-            let Ok(integer) = line[start..stop].trim().parse() else {
-                return Err(AceError::Format)
+            let Some(Ok(value)) = line.get(start..stop).map(|field| field.trim().parse()) else {
+                return Err(AceError::Format);
             };
-            nxs.push(integer);
+            *integer = value;
         }
     }
-    Ok(nxs)
+    Ok(array)
 }
 
 fn parse_xss_array(lines: &mut Lines, size: usize) -> Result<Vec<f64>, AceError> {
     let mut xss = Vec::with_capacity(size);
+    // last line may hold less than 4 values
     for line in lines {
         for i in 0..4 {
+            if xss.len() == size {
+                return Ok(xss);
+            }
             let start = i * 20;
             let stop = i * 20 + 20;
-            let Ok(float) = line[start..stop].trim().parse() else {
-                return Err(AceError::Format)
+            let Some(field) = line.get(start..stop) else {
+                return Err(AceError::Format);
+            };
+            let Ok(float) = field.trim().parse() else {
+                return Err(AceError::Format);
             };
             xss.push(float);
         }
     }
+    if xss.len() < size {
+        return Err(AceError::Format);
+    }
     Ok(xss)
 }
@@ -1,3 +1,13 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::{Jxs, Nxs};
+use crate::core::Zai;
+
+/// Boltzmann constant [MeV/K].
+pub const BOLTZMANN_CONSTANT: f64 = 8.617_333_262e-11;
+
 /// ACE Table.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Table {
@@ -5,8 +15,8 @@ pub struct Table {
     pub(crate) atomic_weight_ratio: f64,
     pub(crate) temperature: f64,
     pub(crate) izaw: Vec<(u32, f64)>,
-    pub(crate) nxs: Vec<usize>,
-    pub(crate) jxs: Vec<usize>,
+    pub(crate) nxs: Nxs,
+    pub(crate) jxs: Jxs,
     pub(crate) xss: Vec<f64>,
 }
 
@@ -21,11 +31,27 @@ impl Table {
         self.atomic_weight_ratio
     }
 
-    /// Returns table's temperature.
+    /// Returns table's temperature as thermal energy `kT` in MeV.
+    ///
+    /// Refer to [`Table::temperature_kelvin`] for temperature in kelvin.
     pub fn temperature(&self) -> f64 {
         self.temperature
     }
 
+    /// Returns table's temperature in kelvin.
+    ///
+    /// # Format
+    ///
+    /// ACE tables store temperature as thermal energy `kT` in MeV, converted
+    /// with the [Boltzmann constant](BOLTZMANN_CONSTANT) `k`:
+    ///
+    /// ```text
+    /// T = kT / k
+    /// ```
+    pub fn temperature_kelvin(&self) -> f64 {
+        self.temperature / BOLTZMANN_CONSTANT
+    }
+
     /// Returns table's izaw array.
     pub fn izaw(&self) -> &[(u32, f64)] {
         &self.izaw
@@ -33,11 +59,21 @@ impl Table {
 
     /// Returns table's nxs array.
     pub fn nxs(&self) -> &[usize] {
-        &self.nxs
+        self.nxs.as_slice()
     }
 
     /// Returns table's jxs array.
     pub fn jxs(&self) -> &[usize] {
+        self.jxs.as_slice()
+    }
+
+    /// Returns table's nxs array with named accessors.
+    pub fn named_nxs(&self) -> &Nxs {
+        &self.nxs
+    }
+
+    /// Returns table's jxs array with named accessors.
+    pub fn named_jxs(&self) -> &Jxs {
         &self.jxs
     }
 
@@ -45,4 +81,405 @@ impl Table {
     pub fn xss(&self) -> &[f64] {
         &self.xss
     }
+
+    /// Returns table's type from the class letter ending its id.
+    ///
+    /// Refer to [`TableType`] for recognized class letters.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(table_type)` if id ends with a recognized class letter
+    /// - `None` otherwise
+    pub fn table_type(&self) -> Option<TableType> {
+        let (_, suffix) = self.id.rsplit_once('.')?;
+        TableType::from_letter(suffix.chars().last()?)
+    }
+
+    /// Returns `true` if table is a continuous-energy neutron table (`c` class letter).
+    ///
+    /// Refer to [`Table::table_type`].
+    pub fn is_continuous_energy(&self) -> bool {
+        self.table_type() == Some(TableType::Continuous)
+    }
+
+    /// Returns `true` if table is a thermal scattering law S(α,β) table (`t` class letter).
+    ///
+    /// Refer to [`Table::table_type`].
+    pub fn is_thermal_scattering(&self) -> bool {
+        self.table_type() == Some(TableType::Thermal)
+    }
+
+    /// Returns `true` if table is a continuous-energy photoatomic table (`p` class letter).
+    ///
+    /// Refer to [`Table::table_type`].
+    pub fn is_photoatomic(&self) -> bool {
+        self.table_type() == Some(TableType::Photoatomic)
+    }
+
+    /// Returns `true` if table is a dosimetry table (`y` class letter).
+    ///
+    /// Refer to [`Table::table_type`].
+    pub fn is_dosimetry(&self) -> bool {
+        self.table_type() == Some(TableType::Dosimetry)
+    }
+
+    /// Returns table's XSS array length, NXS(1).
+    pub fn xss_length(&self) -> usize {
+        self.nxs.xss_length()
+    }
+
+    /// Returns table's ZAID number, NXS(2).
+    ///
+    /// For continuous-energy neutron tables, ZAID number is the `ZA` number
+    /// of the target nuclide: `ZA = Z × 1000 + A`.
+    pub fn zaid_number(&self) -> usize {
+        self.nxs.za()
+    }
+
+    /// Returns table's energy grid length, NXS(3).
+    pub fn energy_grid_length(&self) -> usize {
+        self.nxs.energy_grid_length()
+    }
+
+    /// Returns table's target nuclide from its ZAID number, NXS(2).
+    ///
+    /// # Returns
+    ///
+    /// - `Some(zai)` if ZAID number is a conformant `ZA` number
+    /// - `None` otherwise
+    ///
+    /// # Notes
+    ///
+    /// `ZA` numbers do not carry isomeric state: isomeric state number is always `0`.
+    pub fn zai(&self) -> Option<Zai> {
+        Zai::from_za(self.zaid_number().try_into().ok()?)
+    }
+
+    /// Returns table's energy grid from the ESZ block.
+    ///
+    /// # Format
+    ///
+    /// ESZ block starts at XSS index JXS(1) and holds five consecutive arrays
+    /// of NXS(3) values each:
+    ///
+    /// | Array | Content                               |
+    /// |-------|---------------------------------------|
+    /// | 1     | energy grid (MeV)                     |
+    /// | 2     | total cross section (b)               |
+    /// | 3     | total disappearance cross section (b) |
+    /// | 4     | elastic cross section (b)             |
+    /// | 5     | average heating number (MeV)          |
+    ///
+    /// # Returns
+    ///
+    /// Energy grid, or an empty slice if JXS(1) and NXS(3) do not locate
+    /// the ESZ block inside the XSS array.
+    pub fn energy_grid(&self) -> &[f64] {
+        self.esz_array(0)
+    }
+
+    /// Returns table's total cross section from the ESZ block.
+    ///
+    /// Refer to [`Table::energy_grid`] for ESZ block layout.
+    ///
+    /// # Returns
+    ///
+    /// Total cross section, or an empty slice if JXS(1) and NXS(3) do not
+    /// locate the ESZ block inside the XSS array.
+    pub fn total_cross_section(&self) -> &[f64] {
+        self.esz_array(1)
+    }
+
+    /// Returns `true` if tables are equal within a relative tolerance on floats.
+    ///
+    /// Id, NXS and JXS arrays, IZAW integers and XSS length are compared
+    /// exactly, while atomic weight ratio, temperature, IZAW floats and XSS
+    /// values `a` and `b` are considered equal if:
+    ///
+    /// ```text
+    /// |a - b| <= epsilon × max(|a|, |b|)
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Unlike `==`, this comparison is robust to rounding of floats, e.g. for
+    /// tables round-tripped through text.
+    pub fn approx_eq(&self, other: &Table, epsilon: f64) -> bool {
+        let float_eq = |lhs: f64, rhs: f64| {
+            lhs == rhs || (lhs - rhs).abs() <= epsilon * lhs.abs().max(rhs.abs())
+        };
+        self.id == other.id
+            && self.nxs == other.nxs
+            && self.jxs == other.jxs
+            && float_eq(self.atomic_weight_ratio, other.atomic_weight_ratio)
+            && float_eq(self.temperature, other.temperature)
+            && self.izaw.len() == other.izaw.len()
+            && self
+                .izaw
+                .iter()
+                .zip(&other.izaw)
+                .all(|(&(lhs_za, lhs_aw), &(rhs_za, rhs_aw))| {
+                    lhs_za == rhs_za && float_eq(lhs_aw, rhs_aw)
+                })
+            && self.xss.len() == other.xss.len()
+            && self
+                .xss
+                .iter()
+                .zip(&other.xss)
+                .all(|(&lhs, &rhs)| float_eq(lhs, rhs))
+    }
+
+    // Returns `index`-th array of the ESZ block, or an empty slice if the
+    // block does not fit in the XSS array.
+    fn esz_array(&self, index: usize) -> &[f64] {
+        let length = self.energy_grid_length();
+        let Some(start) = self.jxs.energy_table().checked_sub(1) else {
+            return &[];
+        };
+        let Some(end) = length
+            .checked_mul(5)
+            .and_then(|size| size.checked_add(start))
+        else {
+            return &[];
+        };
+        if end > self.xss.len() {
+            return &[];
+        }
+        let start = start + index * length;
+        &self.xss[start..start + length]
+    }
+}
+
+/// ACE table type.
+///
+/// Table type is identified by the class letter ending table's id (e.g.
+/// `c` in `92235.80c`):
+///
+/// | Letter | Table type                                  |
+/// |--------|---------------------------------------------|
+/// | `c`    | continuous-energy neutron                   |
+/// | `d`    | discrete-reaction neutron                   |
+/// | `y`    | dosimetry                                   |
+/// | `t`    | thermal scattering law S(α,β)               |
+/// | `p`    | continuous-energy photoatomic               |
+/// | `u`    | continuous-energy photonuclear              |
+/// | `e`    | continuous-energy electron                  |
+/// | `m`    | multigroup neutron                          |
+/// | `g`    | multigroup photon                           |
+/// | `h`    | continuous-energy proton                    |
+/// | `o`    | continuous-energy deuteron                  |
+/// | `r`    | continuous-energy triton                    |
+/// | `s`    | continuous-energy helium-3                  |
+/// | `a`    | continuous-energy alpha                     |
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TableType {
+    /// Continuous-energy neutron table.
+    Continuous,
+    /// Discrete-reaction neutron table.
+    Discrete,
+    /// Dosimetry table.
+    Dosimetry,
+    /// Thermal scattering law S(α,β) table.
+    Thermal,
+    /// Continuous-energy photoatomic table.
+    Photoatomic,
+    /// Continuous-energy photonuclear table.
+    Photonuclear,
+    /// Continuous-energy electron table.
+    Electron,
+    /// Multigroup neutron table.
+    MultigroupNeutron,
+    /// Multigroup photon table.
+    MultigroupPhoton,
+    /// Continuous-energy proton table.
+    Proton,
+    /// Continuous-energy deuteron table.
+    Deuteron,
+    /// Continuous-energy triton table.
+    Triton,
+    /// Continuous-energy helium-3 table.
+    Helium3,
+    /// Continuous-energy alpha table.
+    Alpha,
+}
+
+impl TableType {
+    // Returns table type identified by its class letter.
+    fn from_letter(letter: char) -> Option<TableType> {
+        match letter {
+            'c' => Some(TableType::Continuous),
+            'd' => Some(TableType::Discrete),
+            'y' => Some(TableType::Dosimetry),
+            't' => Some(TableType::Thermal),
+            'p' => Some(TableType::Photoatomic),
+            'u' => Some(TableType::Photonuclear),
+            'e' => Some(TableType::Electron),
+            'm' => Some(TableType::MultigroupNeutron),
+            'g' => Some(TableType::MultigroupPhoton),
+            'h' => Some(TableType::Proton),
+            'o' => Some(TableType::Deuteron),
+            'r' => Some(TableType::Triton),
+            's' => Some(TableType::Helium3),
+            'a' => Some(TableType::Alpha),
+            _ => None,
+        }
+    }
+
+    // Returns table type's class letter.
+    fn letter(&self) -> char {
+        match self {
+            TableType::Continuous => 'c',
+            TableType::Discrete => 'd',
+            TableType::Dosimetry => 'y',
+            TableType::Thermal => 't',
+            TableType::Photoatomic => 'p',
+            TableType::Photonuclear => 'u',
+            TableType::Electron => 'e',
+            TableType::MultigroupNeutron => 'm',
+            TableType::MultigroupPhoton => 'g',
+            TableType::Proton => 'h',
+            TableType::Deuteron => 'o',
+            TableType::Triton => 'r',
+            TableType::Helium3 => 's',
+            TableType::Alpha => 'a',
+        }
+    }
+}
+
+impl Display for TableType {
+    /// Formats `TableType` as its class letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::ace::TableType;
+    ///
+    /// assert_eq!(TableType::Continuous.to_string(), "c");
+    /// assert_eq!(TableType::Thermal.to_string(), "t");
+    /// ```
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "{}", self.letter())
+    }
+}
+
+impl FromStr for TableType {
+    type Err = ParseTableTypeError;
+
+    /// Parses `TableType` from its class letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::ace::TableType;
+    ///
+    /// assert_eq!("c".parse::<TableType>().unwrap(), TableType::Continuous);
+    /// assert!("x".parse::<TableType>().is_err());
+    /// assert!("ct".parse::<TableType>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => Self::from_letter(letter).ok_or(ParseTableTypeError),
+            _ => Err(ParseTableTypeError),
+        }
+    }
+}
+
+/// Error returned when parsing a [`TableType`] from a string fails.
+#[derive(Debug)]
+pub struct ParseTableTypeError;
+
+impl Display for ParseTableTypeError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "parse ACE table type error")
+    }
+}
+
+impl Error for ParseTableTypeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(id: &str) -> Table {
+        Table {
+            id: id.to_owned(),
+            atomic_weight_ratio: 1.0,
+            temperature: 0.0,
+            izaw: Vec::new(),
+            nxs: Nxs([0; 16]),
+            jxs: Jxs([0; 32]),
+            xss: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn table_type() {
+        assert_eq!(table("12345.12c").table_type(), Some(TableType::Continuous));
+        assert_eq!(
+            table("1123123.123c").table_type(),
+            Some(TableType::Continuous)
+        );
+        assert_eq!(table("lwtr.20t").table_type(), Some(TableType::Thermal));
+        assert_eq!(table("1000.12p").table_type(), Some(TableType::Photoatomic));
+        assert_eq!(table("12345.12x").table_type(), None);
+        assert_eq!(table("12345").table_type(), None);
+        assert_eq!(table("12345.").table_type(), None);
+    }
+
+    #[test]
+    fn approx_eq() {
+        let mut lhs = table("92235.80c");
+        lhs.izaw = vec![(1001, 0.9991673)];
+        lhs.xss = vec![1.0e-11, 2.5, 0.0];
+        let mut rhs = lhs.clone();
+        rhs.xss[1] = f64::from_bits(rhs.xss[1].to_bits() + 1);
+        assert!(lhs != rhs);
+        assert!(lhs.approx_eq(&rhs, f64::EPSILON));
+        assert!(!lhs.approx_eq(&rhs, 0.0));
+        rhs.xss[1] = 2.51;
+        assert!(!lhs.approx_eq(&rhs, 1e-3));
+        assert!(lhs.approx_eq(&rhs, 1e-2));
+        rhs.xss.push(0.0);
+        assert!(!lhs.approx_eq(&rhs, 1e-2));
+        let mut rhs = lhs.clone();
+        rhs.nxs.0[0] = 3;
+        assert!(!lhs.approx_eq(&rhs, 1e-2));
+        let mut rhs = lhs.clone();
+        rhs.izaw[0].0 = 1002;
+        assert!(!lhs.approx_eq(&rhs, 1e-2));
+    }
+
+    #[test]
+    fn table_type_round_trip() {
+        for table_type in [
+            TableType::Continuous,
+            TableType::Discrete,
+            TableType::Dosimetry,
+            TableType::Thermal,
+            TableType::Photoatomic,
+            TableType::Photonuclear,
+            TableType::Electron,
+            TableType::MultigroupNeutron,
+            TableType::MultigroupPhoton,
+            TableType::Proton,
+            TableType::Deuteron,
+            TableType::Triton,
+            TableType::Helium3,
+            TableType::Alpha,
+        ] {
+            let letter = table_type.to_string();
+            assert_eq!(letter.len(), 1);
+            assert_eq!(letter.parse::<TableType>().unwrap(), table_type);
+            assert_eq!(
+                table(&format!("1001.80{letter}")).table_type(),
+                Some(table_type)
+            );
+        }
+        assert!("".parse::<TableType>().is_err());
+        assert!("x".parse::<TableType>().is_err());
+        assert!("C".parse::<TableType>().is_err());
+        assert!("cc".parse::<TableType>().is_err());
+    }
 }
@@ -7,29 +7,56 @@
 //!
 //! All records available in ENDF-6 format are available:
 //! - [`Cont`]
+//! - [`Head`]
 //! - [`Intg`]
 //! - [`List`]
 //! - [`Tab1`]
 //! - [`Tab2`]
 //! - [`Text`]
 //!
+//! Directory entries of **MF=1/MT=451** section are represented by [`DirEntry`].
+//!
 //! # Parsing
 //!
 //! A *low level* API is available for parsing standard ENDF-6 format primitives:
 //! - [`parse_endf_integer`]
+//! - [`parse_endf_integer_as`]
 //! - [`parse_endf_float`]
+//! - [`parse_endf_float_width`]
+//!
+//! and its inverse [`format_endf_float`].
 //!
 //! A *mid level* API is available for standard partial record parsing:
 //! - [`parse_integer`]
 //! - [`parse_float`]
 //! - [`parse_cont`]
+//! - [`parse_head`]
 //! - [`parse_text`]
+//! - [`parse_list`]
+//! - [`parse_tab1`]
+//! - [`parse_tab2`]
 //! - [`parse_material`]
 //! - [`parse_file`]
 //! - [`parse_section`]
 //! - [`parse_sequence`]
 //!
-//! A *high level* API available through the [`EndfReader`] struct.
+//! Terminator records are identified from control numbers with:
+//! - [`is_send`]
+//! - [`is_fend`]
+//! - [`is_mend`]
+//! - [`is_tend`]
+//!
+//! or classified with [`record_kind`].
+//!
+//! Standard file and section numbers are named with [`mf_name`] and
+//! [`mt_name`].
+//!
+//! A *high level* API available through the [`EndfReader`] struct, and the
+//! [`Tape`] struct indexing a whole tape by material/file/section.
+//! Gzip-compressed files can be read with `EndfReader::from_gzip` (requires
+//! the `flate2` feature).
+//!
+//! Records are written back in ENDF-6 format through the [`EndfWriter`] struct.
 //!
 //! # References
 //!
@@ -42,19 +69,31 @@ pub use error::EndfError;
 
 // Primitives
 mod integer;
-pub use integer::{parse_endf_integer, ParseEndfIntegerError};
+pub use integer::{parse_endf_integer, parse_endf_integer_as, ParseEndfIntegerError};
 
 mod float;
-pub use float::{parse_endf_float, ParseEndfFloatError};
+pub use float::{format_endf_float, parse_endf_float, parse_endf_float_width, ParseEndfFloatError};
 
 // Records
 mod records;
-pub use records::{Cont, Intg, List, Tab1, Tab2, Text};
+pub use records::{Cont, DirEntry, Head, Intg, List, RecordKind, Tab1, Tab2, Text};
+
+// Names
+mod names;
+pub use names::{mf_name, mt_name};
 
 // Reader
 mod read;
 pub use read::EndfReader;
 
+// Writer
+mod write;
+pub use write::EndfWriter;
+
+// Tape
+mod tape;
+pub use tape::Tape;
+
 /// Parse ENDF integer at specified column in `record`.
 ///
 /// # Format
@@ -68,7 +107,7 @@ pub use read::EndfReader;
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid format of the record
+/// - invalid format of the record (shorter than `column × 11` bytes)
 /// - invalid data of the record
 /// - invalid integer format
 ///
@@ -106,7 +145,7 @@ pub fn parse_integer<R: AsRef<[u8]>>(record: R, column: usize) -> Result<i64, En
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid format of the record
+/// - invalid format of the record (shorter than `column × 11` bytes)
 /// - invalid data of the record
 /// - invalid float format
 ///
@@ -149,7 +188,7 @@ pub fn parse_float<R: AsRef<[u8]>>(record: R, column: usize) -> Result<f64, Endf
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid format of the record
+/// - invalid format of the record (shorter than 66 bytes)
 /// - invalid data of the record
 /// - parsing float from column 1 or 2 failed
 /// - parsing integer from column 3-6 failed
@@ -180,6 +219,43 @@ pub fn parse_cont<R: AsRef<[u8]>>(record: R) -> Result<Cont, EndfError> {
     Ok(Cont(c1, c2, l1, l2, n1, n2))
 }
 
+/// Parse ENDF `HEAD` record.
+///
+/// # Format
+///
+/// This function assume following format:
+///
+/// ```text
+/// record = za[11] awr[11] integer[11] integer[11] integer[11] integer[11]
+/// ```
+///
+/// `ZA` and `AWR` are floating point numbers. Refer to [`parse_cont`] for
+/// integers and floating point numbers format.
+///
+/// # Errors
+///
+/// [`EndfError`] is returned if:
+/// - invalid format of the record (shorter than 66 bytes)
+/// - invalid data of the record
+/// - parsing `ZA`/`AWR` from column 1 or 2 failed
+/// - parsing integer from column 3-6 failed
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::parse_head;
+/// use nkl::data::endf::Head;
+///
+/// let record = b" 92235.0000 233.024800          0          0          0          09228 1451    1";
+/// let Head(za, awr, _, _, _, _) = parse_head(record).unwrap();
+/// assert_eq!(za, 92235.0);
+/// assert_eq!(awr, 233.0248);
+/// ```
+pub fn parse_head<R: AsRef<[u8]>>(record: R) -> Result<Head, EndfError> {
+    let Cont(za, awr, l1, l2, n1, n2) = parse_cont(record)?;
+    Ok(Head(za, awr, l1, l2, n1, n2))
+}
+
 /// Parse ENDF `TEXT` record.
 ///
 /// # Format
@@ -193,8 +269,9 @@ pub fn parse_cont<R: AsRef<[u8]>>(record: R) -> Result<Cont, EndfError> {
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid format of the record
-/// - invalid data of the record
+/// - invalid format of the record (shorter than 66 bytes)
+/// - invalid encoding of the record (non ASCII text):
+///   [`EndfError::Encoding`]
 ///
 /// # Examples
 ///
@@ -209,14 +286,257 @@ pub fn parse_cont<R: AsRef<[u8]>>(record: R) -> Result<Cont, EndfError> {
 pub fn parse_text<R: AsRef<[u8]>>(record: R) -> Result<Text, EndfError> {
     let record = record.as_ref();
     match record.get(0..66) {
-        Some(slice) => match String::from_utf8(slice.to_vec()) {
-            Ok(hl) => Ok(Text(hl)),
-            Err(_) => Err(EndfError::Data),
-        },
+        // ENDF text is ASCII: a byte is a char
+        Some(slice) if slice.is_ascii() => Ok(Text(slice.iter().map(|&b| b as char).collect())),
+        Some(_) => Err(EndfError::Encoding),
         None => Err(EndfError::Format),
     }
 }
 
+/// Parse ENDF `LIST` record from `lines`.
+///
+/// # Format
+///
+/// This function assume following format:
+///
+/// ```text
+/// lines[0] = cont (C1, C2, L1, L2, NPL, N2)
+/// lines[1..] = float[6] (B1, ..., BNPL)
+/// ```
+///
+/// Lines following the record are ignored.
+///
+/// # Errors
+///
+/// [`EndfError`] is returned if:
+/// - `lines` hold less lines than the record
+/// - invalid format of the lines (shorter than 66 bytes)
+/// - invalid data of the lines
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::{parse_list, List};
+///
+/// let lines = [
+///     " 1.00000000 2.00000000          1          2          3          4123412123    1",
+///     " 1.00000000 2.00000000 3.00000000                                 123412123    2",
+/// ];
+/// let list = parse_list(&lines).unwrap();
+/// assert_eq!(list, List(1., 2., 1, 2, 3, 4, vec![1., 2., 3.]));
+/// ```
+pub fn parse_list<L: AsRef<[u8]>>(lines: &[L]) -> Result<List, EndfError> {
+    list_from(records_from(lines))
+}
+
+/// Parse ENDF `TAB1` record from `lines`.
+///
+/// # Format
+///
+/// This function assume following format:
+///
+/// ```text
+/// lines[0] = cont (C1, C2, L1, L2, NR, NP)
+/// lines[1..] = integer[6] (NBT1, INT1, ..., NBTNR, INTNR)
+/// lines[..] = float[6] (X1, Y1, ..., XNP, YNP)
+/// ```
+///
+/// Lines following the record are ignored.
+///
+/// # Errors
+///
+/// [`EndfError`] is returned if:
+/// - `lines` hold less lines than the record
+/// - invalid format of the lines (shorter than 66 bytes)
+/// - invalid data of the lines
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::{parse_tab1, Tab1};
+///
+/// let lines = [
+///     " 1.00000000 2.00000000          1          2          1          2123412123    1",
+///     "          2          2                                            123412123    2",
+///     " 1.00000000 2.00000000 3.00000000 4.00000000                      123412123    3",
+/// ];
+/// let tab1 = parse_tab1(&lines).unwrap();
+/// assert_eq!(tab1, Tab1(1., 2., 1, 2, 1, 2, vec![(2, 2)], vec![(1., 2.), (3., 4.)]));
+/// ```
+pub fn parse_tab1<L: AsRef<[u8]>>(lines: &[L]) -> Result<Tab1, EndfError> {
+    tab1_from(records_from(lines))
+}
+
+/// Parse ENDF `TAB2` record from `lines`.
+///
+/// # Format
+///
+/// This function assume following format:
+///
+/// ```text
+/// lines[0] = cont (C1, C2, L1, L2, NR, NZ)
+/// lines[1..] = integer[6] (NBT1, INT1, ..., NBTNR, INTNR)
+/// ```
+///
+/// Lines following the record are ignored.
+///
+/// # Errors
+///
+/// [`EndfError`] is returned if:
+/// - `lines` hold less lines than the record
+/// - invalid format of the lines (shorter than 66 bytes)
+/// - invalid data of the lines
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::{parse_tab2, Tab2};
+///
+/// let lines = [
+///     " 1.00000000 2.00000000          1          2          1          3123412123    1",
+///     "          3          2                                            123412123    2",
+/// ];
+/// let tab2 = parse_tab2(&lines).unwrap();
+/// assert_eq!(tab2, Tab2(1., 2., 1, 2, 1, 3, vec![(3, 2)]));
+/// ```
+pub fn parse_tab2<L: AsRef<[u8]>>(lines: &[L]) -> Result<Tab2, EndfError> {
+    tab2_from(records_from(lines))
+}
+
+// Returns a record source copying successive `lines` into the record buffer.
+fn records_from<L: AsRef<[u8]>>(
+    lines: &[L],
+) -> impl FnMut(&mut Vec<u8>) -> Result<(), EndfError> + '_ {
+    let mut lines = lines.iter();
+    move |buf| match lines.next() {
+        Some(line) => {
+            buf.clear();
+            buf.extend_from_slice(line.as_ref());
+            Ok(())
+        }
+        None => Err(EndfError::EndOfFile),
+    }
+}
+
+// Parse a LIST record from records read by `next` into a shared buffer.
+fn list_from(
+    mut next: impl FnMut(&mut Vec<u8>) -> Result<(), EndfError>,
+) -> Result<List, EndfError> {
+    let mut buf = Vec::new();
+    next(&mut buf)?;
+    let Cont(c1, c2, l1, l2, npl, n2) = parse_cont(&buf)?;
+    let npl: usize = match npl.try_into() {
+        Ok(npl) => npl,
+        Err(_) => return Err(EndfError::Data),
+    };
+    let mut b = Vec::with_capacity(npl);
+    while b.len() < npl {
+        next(&mut buf)?;
+        for col in 0..6 {
+            if b.len() == npl {
+                break;
+            }
+            let float = parse_float(&buf, col + 1)?;
+            b.push(float);
+        }
+    }
+    Ok(List(c1, c2, l1, l2, npl, n2, b))
+}
+
+// Parse a TAB1 record from records read by `next` into a shared buffer.
+fn tab1_from(next: impl FnMut(&mut Vec<u8>) -> Result<(), EndfError>) -> Result<Tab1, EndfError> {
+    let mut int = Vec::new();
+    let mut tab = Vec::new();
+    let (c1, c2, l1, l2) = tab1_into(next, &mut int, &mut tab)?;
+    Ok(Tab1(c1, c2, l1, l2, int.len(), tab.len(), int, tab))
+}
+
+// Parse a TAB1 record into caller's `int` and `tab` buffers (cleared first),
+// returning `(C1, C2, L1, L2)`.
+fn tab1_into(
+    mut next: impl FnMut(&mut Vec<u8>) -> Result<(), EndfError>,
+    int: &mut Vec<(u32, usize)>,
+    tab: &mut Vec<(f64, f64)>,
+) -> Result<(f64, f64, i64, i64), EndfError> {
+    let mut buf = Vec::new();
+    next(&mut buf)?;
+    let Cont(c1, c2, l1, l2, nr, np) = parse_cont(&buf)?;
+    let nr: usize = match nr.try_into() {
+        Ok(nr) => nr,
+        Err(_) => return Err(EndfError::Data),
+    };
+    let np: usize = match np.try_into() {
+        Ok(np) => np,
+        Err(_) => return Err(EndfError::Data),
+    };
+    interpolation_into(&mut next, &mut buf, nr, int)?;
+    tab.clear();
+    tab.reserve(np);
+    while tab.len() < np {
+        next(&mut buf)?;
+        for col in 0..3 {
+            if tab.len() == np {
+                break;
+            }
+            let x = parse_float(&buf, 2 * col + 1)?;
+            let y = parse_float(&buf, 2 * col + 2)?;
+            tab.push((x, y));
+        }
+    }
+    Ok((c1, c2, l1, l2))
+}
+
+// Parse a TAB2 record from records read by `next` into a shared buffer.
+fn tab2_from(
+    mut next: impl FnMut(&mut Vec<u8>) -> Result<(), EndfError>,
+) -> Result<Tab2, EndfError> {
+    let mut buf = Vec::new();
+    next(&mut buf)?;
+    let Cont(c1, c2, l1, l2, nr, nz) = parse_cont(&buf)?;
+    let nr: usize = match nr.try_into() {
+        Ok(nr) => nr,
+        Err(_) => return Err(EndfError::Data),
+    };
+    let nz: usize = match nz.try_into() {
+        Ok(nz) => nz,
+        Err(_) => return Err(EndfError::Data),
+    };
+    let mut int = Vec::new();
+    interpolation_into(&mut next, &mut buf, nr, &mut int)?;
+    Ok(Tab2(c1, c2, l1, l2, nr, nz, int))
+}
+
+// Parse `nr` interpolation regions `(NBT, INT)` of a TAB1/TAB2 record into `int` (cleared first).
+fn interpolation_into(
+    next: &mut impl FnMut(&mut Vec<u8>) -> Result<(), EndfError>,
+    buf: &mut Vec<u8>,
+    nr: usize,
+    int: &mut Vec<(u32, usize)>,
+) -> Result<(), EndfError> {
+    int.clear();
+    int.reserve(nr);
+    while int.len() < nr {
+        next(buf)?;
+        for col in 0..3 {
+            if int.len() == nr {
+                break;
+            }
+            let nbt = parse_integer(&buf[..], 2 * col + 1)?;
+            let nbt: u32 = match nbt.try_into() {
+                Ok(nbt) => nbt,
+                Err(_) => return Err(EndfError::Data),
+            };
+            let scheme = parse_integer(&buf[..], 2 * col + 2)?;
+            let scheme: usize = match scheme.try_into() {
+                Ok(scheme) => scheme,
+                Err(_) => return Err(EndfError::Data),
+            };
+            int.push((nbt, scheme));
+        }
+    }
+    Ok(())
+}
+
 type ControlNumbers = (i32, u32, u32, Option<u32>);
 
 /// Parse ENDF record control numbers.
@@ -226,16 +546,23 @@ type ControlNumbers = (i32, u32, u32, Option<u32>);
 /// This function assume following format:
 ///
 /// ```text
-/// record = any[66] mat[4] mf[2] mt[3] ns[5]?
+/// record = any[66] (mat[4] mf[2] mt[3] ns[5]?)?
 /// ```
 ///
 /// Integers format are described in relevant section available in
 /// [`parse_endf_integer`](crate::data::endf::parse_endf_integer).
 ///
+/// # Returns
+///
+/// - `Ok(Some((mat, mf, mt, ns)))` if the record holds control numbers
+/// - `Ok(None)` if the record holds no control numbers: nothing but blanks
+///   after column 66 (e.g. line trimmed by a tool stripping trailing
+///   whitespace)
+///
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid format of the record
+/// - invalid format of the record (control numbers present but shorter than 75 bytes)
 /// - invalid data of the record
 /// - parsing MAT/MF/MT/NS control number failed
 ///
@@ -244,19 +571,28 @@ type ControlNumbers = (i32, u32, u32, Option<u32>);
 /// ```
 /// use nkl::data::endf::parse_control_numbers;
 /// let record = " 1.23456789-1.23456789          1          2          3          412341212312345";
-/// let (mat, mf, mt, ns) = parse_control_numbers(record).unwrap();
+/// let (mat, mf, mt, ns) = parse_control_numbers(record).unwrap().unwrap();
 /// assert_eq!(mat, 1234);
 /// assert_eq!(mf, 12);
 /// assert_eq!(mt, 123);
-/// assert_eq!(ns, Some(12345))
+/// assert_eq!(ns, Some(12345));
+///
+/// let record = " 1.23456789-1.23456789          1          2          3          4";
+/// assert_eq!(parse_control_numbers(record).unwrap(), None);
 /// ```
-pub fn parse_control_numbers<R: AsRef<[u8]>>(record: R) -> Result<ControlNumbers, EndfError> {
+pub fn parse_control_numbers<R: AsRef<[u8]>>(
+    record: R,
+) -> Result<Option<ControlNumbers>, EndfError> {
     let record = record.as_ref();
+    let controls = record.get(66..).unwrap_or_default();
+    if controls.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
     let mat = parse_material(record)?;
     let mf = parse_file(record)?;
     let mt = parse_section(record)?;
     let ns = parse_sequence(record)?;
-    Ok((mat, mf, mt, ns))
+    Ok(Some((mat, mf, mt, ns)))
 }
 
 /// Parse ENDF *MAT* material control number in `record`.
@@ -271,7 +607,7 @@ pub fn parse_control_numbers<R: AsRef<[u8]>>(record: R) -> Result<ControlNumbers
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid record format
+/// - invalid record format (shorter than 70 bytes)
 /// - parsing MAT control number failed
 ///
 /// # Examples
@@ -306,7 +642,7 @@ pub fn parse_material<R: AsRef<[u8]>>(record: R) -> Result<i32, EndfError> {
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid record format
+/// - invalid record format (shorter than 72 bytes)
 /// - parsing MF control number failed
 /// - negative file number
 ///
@@ -321,10 +657,7 @@ pub fn parse_material<R: AsRef<[u8]>>(record: R) -> Result<i32, EndfError> {
 pub fn parse_file<R: AsRef<[u8]>>(record: R) -> Result<u32, EndfError> {
     let record = record.as_ref();
     match record.get(70..72) {
-        Some(slice) => match parse_endf_integer(slice) {
-            Ok(integer) => integer.try_into().map_err(|_| EndfError::Data),
-            Err(_) => Err(EndfError::Data),
-        },
+        Some(slice) => parse_endf_integer_as(slice).map_err(|_| EndfError::Data),
         None => Err(EndfError::Format),
     }
 }
@@ -341,7 +674,7 @@ pub fn parse_file<R: AsRef<[u8]>>(record: R) -> Result<u32, EndfError> {
 /// # Errors
 ///
 /// [`EndfError`] is returned if:
-/// - invalid record format
+/// - invalid record format (shorter than 75 bytes)
 /// - parsing MT control number failed
 /// - negative section number
 ///
@@ -356,10 +689,7 @@ pub fn parse_file<R: AsRef<[u8]>>(record: R) -> Result<u32, EndfError> {
 pub fn parse_section<R: AsRef<[u8]>>(record: R) -> Result<u32, EndfError> {
     let record = record.as_ref();
     match record.get(72..75) {
-        Some(slice) => match parse_endf_integer(slice) {
-            Ok(integer) => integer.try_into().map_err(|_| EndfError::Data),
-            Err(_) => Err(EndfError::Data),
-        },
+        Some(slice) => parse_endf_integer_as(slice).map_err(|_| EndfError::Data),
         None => Err(EndfError::Format),
     }
 }
@@ -369,7 +699,7 @@ pub fn parse_section<R: AsRef<[u8]>>(record: R) -> Result<u32, EndfError> {
 /// # Format
 ///
 /// The *NS* control number is given in columns 76-80 of the record and is
-/// optional.
+/// optional: `None` is returned for records shorter than 80 bytes.
 ///
 /// ENDF integer format is specified in
 /// [`parse_endf_integer`](crate::data::endf::parse_endf_integer).
@@ -392,17 +722,139 @@ pub fn parse_section<R: AsRef<[u8]>>(record: R) -> Result<u32, EndfError> {
 pub fn parse_sequence<R: AsRef<[u8]>>(record: R) -> Result<Option<u32>, EndfError> {
     let record = record.as_ref();
     match record.get(75..80) {
-        Some(slice) => match parse_endf_integer(slice) {
-            Ok(integer) => match integer.try_into() {
-                Ok(integer) => Ok(Some(integer)),
-                Err(_) => Err(EndfError::Data),
-            },
+        Some(slice) => match parse_endf_integer_as(slice) {
+            Ok(integer) => Ok(Some(integer)),
             Err(_) => Err(EndfError::Data),
         },
         None => Ok(None),
     }
 }
 
+/// Returns `true` if control numbers identify a **SEND** record (end of section).
+///
+/// # Format
+///
+/// ```text
+/// MAT > 0, MF > 0, MT = 0
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::is_send;
+/// assert!(is_send(1234, 12, 0));
+/// assert!(!is_send(1234, 0, 0));
+/// ```
+pub fn is_send(mat: i32, mf: u32, mt: u32) -> bool {
+    mat > 0 && mf > 0 && mt == 0
+}
+
+/// Returns `true` if control numbers identify a **FEND** record (end of file).
+///
+/// # Format
+///
+/// ```text
+/// MAT > 0, MF = 0, MT = 0
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::is_fend;
+/// assert!(is_fend(1234, 0, 0));
+/// assert!(!is_fend(0, 0, 0));
+/// ```
+pub fn is_fend(mat: i32, mf: u32, mt: u32) -> bool {
+    mat > 0 && mf == 0 && mt == 0
+}
+
+/// Returns `true` if control numbers identify a **MEND** record (end of material).
+///
+/// # Format
+///
+/// ```text
+/// MAT = 0, MF = 0, MT = 0
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::is_mend;
+/// assert!(is_mend(0, 0, 0));
+/// assert!(!is_mend(-1, 0, 0));
+/// ```
+pub fn is_mend(mat: i32, mf: u32, mt: u32) -> bool {
+    mat == 0 && mf == 0 && mt == 0
+}
+
+/// Returns `true` if control numbers identify a **TEND** record (end of tape).
+///
+/// # Format
+///
+/// ```text
+/// MAT = -1, MF = 0, MT = 0
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::is_tend;
+/// assert!(is_tend(-1, 0, 0));
+/// assert!(!is_tend(0, 0, 0));
+/// ```
+pub fn is_tend(mat: i32, mf: u32, mt: u32) -> bool {
+    mat == -1 && mf == 0 && mt == 0
+}
+
+/// Returns kind of the ENDF record `line` from its control numbers.
+///
+/// # Format
+///
+/// | Kind                 | MAT  | MF  | MT  |
+/// |----------------------|------|-----|-----|
+/// | [`RecordKind::Send`] | > 0  | > 0 | 0   |
+/// | [`RecordKind::Fend`] | > 0  | 0   | 0   |
+/// | [`RecordKind::Mend`] | 0    | 0   | 0   |
+/// | [`RecordKind::Tend`] | -1   | 0   | 0   |
+/// | [`RecordKind::Data`] | any  | any | > 0 |
+///
+/// # Errors
+///
+/// [`EndfError`] is returned if:
+/// - parsing control numbers failed (see [`parse_control_numbers`]), or record
+///   holds no control numbers
+/// - `MT=0` but control numbers do not identify a terminator record
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::{record_kind, RecordKind};
+///
+/// let line = b" 0.00000000 0.00000000          0          0          0          01234 3  099999";
+/// assert_eq!(record_kind(line).unwrap(), RecordKind::Send);
+/// ```
+///
+/// # Notes
+///
+/// The tape identification record (**TPID**, `MF=0`/`MT=0` with tape number
+/// as `MAT`) is classified as [`RecordKind::Fend`].
+pub fn record_kind<R: AsRef<[u8]>>(line: R) -> Result<RecordKind, EndfError> {
+    let (mat, mf, mt, _) = parse_control_numbers(line)?.ok_or(EndfError::Format)?;
+    if mt != 0 {
+        Ok(RecordKind::Data)
+    } else if is_send(mat, mf, mt) {
+        Ok(RecordKind::Send)
+    } else if is_fend(mat, mf, mt) {
+        Ok(RecordKind::Fend)
+    } else if is_mend(mat, mf, mt) {
+        Ok(RecordKind::Mend)
+    } else if is_tend(mat, mf, mt) {
+        Ok(RecordKind::Tend)
+    } else {
+        Err(EndfError::Data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -435,13 +887,34 @@ mod tests {
     fn controls() {
         let record =
             " 1.23456789-1.23456789          1          2          3          412341212312345";
-        let (mat, mf, mt, ns) = parse_control_numbers(record).unwrap();
+        let (mat, mf, mt, ns) = parse_control_numbers(record).unwrap().unwrap();
         assert_eq!(mat, 1234);
         assert_eq!(mf, 12);
         assert_eq!(mt, 123);
         assert_eq!(ns, Some(12345));
     }
 
+    #[test]
+    fn controls_trimmed() {
+        let record =
+            " 1.23456789-1.23456789          1          2          3          412341212312345";
+        let full = parse_control_numbers(record).unwrap();
+        assert_eq!(full, Some((1234, 12, 123, Some(12345))));
+        let trimmed = parse_control_numbers(&record[..75]).unwrap();
+        assert_eq!(trimmed, Some((1234, 12, 123, None)));
+        assert_eq!(parse_control_numbers(&record[..66]).unwrap(), None);
+        let blank = format!("{}      \r\n", &record[..66]);
+        assert_eq!(parse_control_numbers(blank).unwrap(), None);
+        assert!(matches!(
+            parse_control_numbers(&record[..70]),
+            Err(EndfError::Format)
+        ));
+        assert!(matches!(
+            parse_control_numbers(format!("{}12X4121231", &record[..66])),
+            Err(EndfError::Data)
+        ));
+    }
+
     #[test]
     fn record() {
         let record =
@@ -493,4 +966,40 @@ mod tests {
         let ns = parse_sequence(record.as_bytes()).unwrap();
         assert_eq!(ns, None);
     }
+
+    #[test]
+    fn text_encoding() {
+        let mut record =
+            b"ABCDEFGHIJKLMNOPQRSTUVXYZ abcdefghijklmnopqrstuvxyz 0123456789    12341212312345"
+                .to_vec();
+        assert!(parse_text(&record).is_ok());
+        record[0] = 0xC3;
+        assert!(matches!(parse_text(&record), Err(EndfError::Encoding)));
+    }
+
+    #[test]
+    fn short_record() {
+        let record = " 1.23456789-1.23456789          1          2          3";
+        assert!(matches!(parse_integer(record, 6), Err(EndfError::Format)));
+        assert!(matches!(parse_float(record, 6), Err(EndfError::Format)));
+        assert!(matches!(parse_cont(record), Err(EndfError::Format)));
+        assert!(matches!(parse_text(record), Err(EndfError::Format)));
+        assert!(matches!(parse_control_numbers(record), Ok(None)));
+        assert!(matches!(parse_material(record), Err(EndfError::Format)));
+        assert!(matches!(parse_file(record), Err(EndfError::Format)));
+        assert!(matches!(parse_section(record), Err(EndfError::Format)));
+        assert!(matches!(parse_sequence(record), Ok(None)));
+    }
+
+    #[test]
+    fn short_control_numbers() {
+        let record = " 1.23456789-1.23456789          1          2          3          41234121";
+        assert!(parse_material(record).is_ok());
+        assert!(parse_file(record).is_ok());
+        assert!(matches!(parse_section(record), Err(EndfError::Format)));
+        assert!(matches!(
+            parse_control_numbers(record),
+            Err(EndfError::Format)
+        ));
+    }
 }
@@ -3,7 +3,15 @@ use std::{error::Error as StdError, fmt::Display};
 
 /// The error type for [`endf`](crate::data::endf) module.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum EndfError {
+    /// Error located at specified line (1-based) of the input.
+    At {
+        /// Line number (1-based).
+        line: usize,
+        /// Underlying error.
+        source: Box<EndfError>,
+    },
     /// Invalid data.
     Data,
     /// Invalid encoding.
@@ -19,6 +27,7 @@ pub enum EndfError {
 impl Display for EndfError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            EndfError::At { line, source } => write!(fmt, "ENDF error at line {line}: {source}"),
             EndfError::Data => write!(fmt, "invalid ENDF data"),
             EndfError::Encoding => write!(fmt, "ENDF encoding error"),
             EndfError::EndOfFile => write!(fmt, "reached end of ENDF file"),
@@ -31,6 +40,7 @@ impl Display for EndfError {
 impl StdError for EndfError {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
+            EndfError::At { source, .. } => Some(source.as_ref()),
             EndfError::IO(error) => Some(error),
             _ => None,
         }
@@ -1,5 +1,8 @@
 use std::{error::Error, fmt::Display};
 
+// Maximum length of ENDF floats.
+const ENDF_FLOAT_LENGTH: usize = 11;
+
 /// Power of ten table.
 #[rustfmt::skip]
 const POW_10_TABLE: [f64; 23] = [
@@ -59,11 +62,48 @@ const POW_10_TABLE: [f64; 23] = [
 /// - `float` contains invalid sign/digit
 /// - `float` is only partially parsable
 /// - `float`'s exponential part is empty after exponent separator
+/// - `float` contains only asterisks `*` (fortran output overflow)
 ///
 /// # Notes
 ///
 /// `d` and `D` exponent separator are supported for legacy compatibility.
+///
+/// `NaN` and `Inf` tokens (and their variants) are not valid ENDF floats and
+/// are rejected.
+///
+/// Floats of non-standard width are parsed with [`parse_endf_float_width`].
 pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatError> {
+    parse_endf_float_width(float, ENDF_FLOAT_LENGTH)
+}
+
+/// Parse ENDF float of at most `max_len` characters.
+///
+/// Standard ENDF-6 records hold 11 columns fields, parsed with
+/// [`parse_endf_float`]. Other widths are met with:
+/// - legacy or non-conforming files written with wider fields (e.g. fortran
+///   `E12.5` output of some processing codes)
+/// - fields narrower than 11 columns, split out of a record by the caller
+///
+/// Refer to [`parse_endf_float`] for ENDF float format.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::parse_endf_float_width;
+/// assert_eq!(parse_endf_float_width("-1.23456E-12", 12).unwrap(), -1.23456E-12);
+/// assert!(parse_endf_float_width("-1.23456E-12", 11).is_err());
+/// ```
+///
+/// # Errors
+///
+/// [`ParseEndfFloatError`] is returned if:
+/// - `float.len() > max_len`: too long slice
+/// - `float` mantissa or exponent overflows 64 bits integers (wide fields only)
+/// - `float` is not a valid ENDF float (see [`parse_endf_float`])
+pub fn parse_endf_float_width<F: AsRef<[u8]>>(
+    float: F,
+    max_len: usize,
+) -> Result<f64, ParseEndfFloatError> {
     // Parsing floating point numbers correctly is extremely difficult due to
     // conversion between binary/decimal representation and roundings.
     //
@@ -77,7 +117,7 @@ pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatE
     // - Support fortran blank interpretation mode
     // - Do not incur UTF-8 validation => no conversion to string
     // - Rely on limited floating point numbers length in ENDF format (<= 11)
-    //   => prevent overflow
+    //   => prevent overflow (wider fields are checked)
     // - Rely on std library for non trivial case (described below)
     //
     // The IEEE-754 specification requires that the result of an elementary
@@ -131,10 +171,15 @@ pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatE
         return Err(ParseEndfFloatError);
     }
     // -> too long slice
-    if float.len() > 11 {
+    if float.len() > max_len {
+        return Err(ParseEndfFloatError);
+    }
+    // -> fortran overflow sentinel (e.g. `***********`)
+    if float.contains(&b'*') && float.iter().all(|&b| b == b'*' || b == b' ') {
         return Err(ParseEndfFloatError);
     }
     // - float.len() <= 11 => no mantissa i64 overflow (i64 max digits = 19 > 11)
+    // - wider fields => checked arithmetic
     let mut iter = float.iter().filter(|&b| *b != b' ').peekable();
     // extract sign
     let negative = match iter.peek() {
@@ -156,12 +201,12 @@ pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatE
     }
     // parse mantissa and exponent
     let mut mantissa = 0;
-    let mut exponent = 0;
+    let mut exponent: i64 = 0;
     // parse integral part
     loop {
         match iter.peek() {
             Some(&byte) if byte.is_ascii_digit() => {
-                mantissa = mantissa * 10 + (byte - b'0') as i64; // no overflow
+                mantissa = push_digit(mantissa, *byte)?;
                 iter.next();
             }
             _ => break,
@@ -173,7 +218,7 @@ pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatE
         loop {
             match iter.peek() {
                 Some(&byte) if byte.is_ascii_digit() => {
-                    mantissa = mantissa * 10 + (byte - b'0') as i64; // no overflow
+                    mantissa = push_digit(mantissa, *byte)?;
                     exponent -= 1; // reduce exponent
                     iter.next();
                 }
@@ -213,7 +258,7 @@ pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatE
     loop {
         match iter.peek() {
             Some(&byte) if byte.is_ascii_digit() => {
-                exp = exp * 10 + (byte - b'0') as i32; // no overflow
+                exp = push_digit(exp, *byte)?;
                 iter.next();
             }
             _ => break,
@@ -229,12 +274,13 @@ pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatE
     }
     // compute exponent
     if negative_exponent {
-        exponent -= exp;
+        exponent = exponent.saturating_sub(exp);
     } else {
-        exponent += exp;
+        exponent = exponent.saturating_add(exp);
     }
     // fall back to std library for correct float parsing (IEEE 754) if |exponent| > 22
-    let mut value = if exponent.abs() > 22 {
+    // or mantissa is not exactly representable (wide fields only)
+    let mut value = if exponent.unsigned_abs() > 22 || mantissa > 1 << 53 {
         let float = format!("{mantissa}e{exponent}");
         match float.parse() {
             Ok(value) => value,
@@ -256,6 +302,92 @@ pub fn parse_endf_float<F: AsRef<[u8]>>(float: F) -> Result<f64, ParseEndfFloatE
     Ok(value)
 }
 
+// Appends a decimal digit to `value`, failing on overflow.
+fn push_digit(value: i64, digit: u8) -> Result<i64, ParseEndfFloatError> {
+    value
+        .checked_mul(10)
+        .and_then(|value| value.checked_add((digit - b'0') as i64))
+        .ok_or(ParseEndfFloatError)
+}
+
+/// Format ENDF float.
+///
+/// Floats are formatted as 11 columns right-aligned fields, the first column
+/// holding the sign (blank for positive values). The representation holding
+/// the most significant digits is chosen among:
+/// - fixed notation (e.g. `" 1.23456789"`, 9 significant digits)
+/// - fortran "E-less" notation, where the exponent sign acts as exponent
+///   separator (e.g. `" 1.234567+6"`, 7 significant digits for single digit
+///   exponents)
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::{format_endf_float, parse_endf_float};
+///
+/// assert_eq!(format_endf_float(1.234567e-6), " 1.234567-6");
+/// assert_eq!(format_endf_float(1.234567e6), " 1234567.00");
+/// assert_eq!(format_endf_float(-2.53e-8), "-2.530000-8");
+/// assert_eq!(format_endf_float(235.0439299), " 235.043930");
+/// assert_eq!(format_endf_float(1.0e-10), " 1.00000-10");
+///
+/// let float = format_endf_float(0.0253);
+/// assert_eq!(parse_endf_float(&float).unwrap(), 0.0253);
+/// ```
+///
+/// # Notes
+///
+/// Floats with at most 7 (single digit exponent), 6 (two digits exponent) or
+/// 5 (three digits exponent) significant digits are recovered exactly by
+/// [`parse_endf_float`].
+///
+/// Non finite floats (`NaN` and `Inf`) are formatted as the fortran overflow
+/// sentinel `"***********"`, rejected by [`parse_endf_float`].
+pub fn format_endf_float(float: f64) -> String {
+    if !float.is_finite() {
+        return "*".repeat(ENDF_FLOAT_LENGTH);
+    }
+    let sign = if float.is_sign_negative() { '-' } else { ' ' };
+    let float = float.abs();
+    if float == 0. {
+        return format!("{sign}0.000000+0");
+    }
+    // E-less notation: d.ddddd±e (exponent digits reduce available digits)
+    let mut eless = (0, String::new());
+    for precision in (1..ENDF_FLOAT_LENGTH).rev() {
+        let formatted = format!("{float:.precision$e}");
+        let (mantissa, exponent) = formatted.split_once('e').expect("valid float format");
+        let (exp_sign, exponent) = match exponent.strip_prefix('-') {
+            Some(exponent) => ('-', exponent),
+            None => ('+', exponent),
+        };
+        let length = mantissa.len() + exponent.len() + 2;
+        if length <= ENDF_FLOAT_LENGTH {
+            // rounding may shorten exponent (e.g. 9.999999e-10 -> 1.00000e-9)
+            let padding = "0".repeat(ENDF_FLOAT_LENGTH - length);
+            let field = format!("{sign}{mantissa}{padding}{exp_sign}{exponent}");
+            eless = (precision + 1, field);
+            break;
+        }
+    }
+    // fixed notation: ddd.dddddd
+    let mut fixed = (0, String::new());
+    for precision in (0..ENDF_FLOAT_LENGTH).rev() {
+        let field = format!("{sign}{float:.precision$}");
+        if field.len() <= ENDF_FLOAT_LENGTH {
+            let digits = field
+                .bytes()
+                .filter(u8::is_ascii_digit)
+                .skip_while(|&b| b == b'0')
+                .count();
+            fixed = (digits, field);
+            break;
+        }
+    }
+    let (_, field) = if fixed.0 > eless.0 { fixed } else { eless };
+    format!("{field:>ENDF_FLOAT_LENGTH$}")
+}
+
 /// Error returned when parsing an ENDF float with [`parse_endf_float`] fails.
 #[derive(Debug)]
 pub struct ParseEndfFloatError;
@@ -309,6 +441,24 @@ mod tests {
         assert!(parse_endf_float("1.23e-a").is_err());
     }
 
+    #[test]
+    fn overflow_sentinel() {
+        assert!(parse_endf_float("*********").is_err());
+        assert!(parse_endf_float("***********").is_err());
+        assert!(parse_endf_float("  *********").is_err());
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(parse_endf_float("NaN").is_err());
+        assert!(parse_endf_float("nan").is_err());
+        assert!(parse_endf_float("-NaN").is_err());
+        assert!(parse_endf_float("Inf").is_err());
+        assert!(parse_endf_float("-Inf").is_err());
+        assert!(parse_endf_float("+inf").is_err());
+        assert!(parse_endf_float("Infinity").is_err());
+    }
+
     #[test]
     fn invalid_integral_fractional_separator() {
         assert!(parse_endf_float("1,23").is_err());
@@ -569,4 +719,97 @@ mod tests {
         assert_endf_float_eq("-1.2345E-23", -1.2345e-23);
         assert_endf_float_eq("-1.234E-123", -1.234e-123);
     }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_endf_float(0.), " 0.000000+0");
+        assert_eq!(format_endf_float(1.), " 1.00000000");
+        assert_eq!(format_endf_float(-1.), "-1.00000000");
+        assert_eq!(format_endf_float(0.5), " 0.50000000");
+        assert_eq!(format_endf_float(0.0253), " 2.530000-2");
+        assert_eq!(format_endf_float(1.234567e-6), " 1.234567-6");
+        assert_eq!(format_endf_float(1.234567e6), " 1234567.00");
+        assert_eq!(format_endf_float(123456789.), "  123456789");
+        assert_eq!(format_endf_float(1234567891.), " 1234567891");
+        assert_eq!(format_endf_float(2e10), " 2.00000+10");
+        assert_eq!(format_endf_float(-9.999999e-10), "-1.000000-9");
+        assert_eq!(format_endf_float(1e-300), " 1.0000-300");
+        assert_eq!(format_endf_float(f64::MAX), " 1.7977+308");
+        assert_eq!(format_endf_float(f64::NAN), "***********");
+        assert_eq!(format_endf_float(f64::NEG_INFINITY), "***********");
+    }
+
+    #[test]
+    fn format_width() {
+        for exponent in -320..=308 {
+            for mantissa in [1., 1.5, 3.3333333333, 9.9999999999] {
+                let float = mantissa * 10f64.powi(exponent);
+                assert_eq!(format_endf_float(float).len(), 11);
+                assert_eq!(format_endf_float(-float).len(), 11);
+            }
+        }
+    }
+
+    #[test]
+    fn format_round_trip() {
+        // at least 5 significant digits are recovered for any exponent
+        for exponent in -300..=300 {
+            for mantissa in ["1", "1.5", "2.5301", "6.0221", "9.9999"] {
+                let float: f64 = format!("{mantissa}e{exponent}").parse().unwrap();
+                for float in [float, -float] {
+                    let formatted = format_endf_float(float);
+                    assert_endf_float_eq(&formatted, float);
+                }
+            }
+        }
+        // 7 significant digits for single digit exponents
+        for exponent in -9..=9 {
+            for mantissa in ["1.000001", "2.530000", "6.022141", "9.999999"] {
+                let float: f64 = format!("{mantissa}e{exponent}").parse().unwrap();
+                for float in [float, -float] {
+                    let formatted = format_endf_float(float);
+                    assert_endf_float_eq(&formatted, float);
+                }
+            }
+        }
+        // 9 significant digits in fixed notation
+        for float in [1.23456789, 12.3456789, 235.043930, 1234567.89, 99999999.9] {
+            assert_endf_float_eq(&format_endf_float(float), float);
+        }
+    }
+
+    #[test]
+    fn format_rounding() {
+        for float in [std::f64::consts::PI, 1. / 3., 2. / 3. * 1e-5, 6.02214076e23] {
+            let parsed = parse_endf_float(format_endf_float(float)).unwrap();
+            assert!(((parsed - float) / float).abs() <= 5e-6);
+        }
+    }
+
+    #[test]
+    fn width() {
+        assert_eq!(parse_endf_float_width("1.234567+1", 10).unwrap(), 12.34567);
+        assert!(parse_endf_float_width(" 1.234567+1", 10).is_err());
+        assert_eq!(parse_endf_float_width(" 1.234567+1", 11).unwrap(), 12.34567);
+        assert!(parse_endf_float_width(" 1.23456E-12", 11).is_err());
+        assert_eq!(
+            parse_endf_float_width(" 1.23456E-12", 12).unwrap(),
+            1.23456E-12
+        );
+        assert_eq!(
+            parse_endf_float_width("-1.23456E-12", 12).unwrap(),
+            -1.23456E-12
+        );
+    }
+
+    #[test]
+    fn width_overflow() {
+        let float = "9".repeat(40);
+        assert!(parse_endf_float_width(&float, 40).is_err());
+        let float = "0.12345678901234567";
+        assert_eq!(
+            parse_endf_float_width(float, 20).unwrap(),
+            0.12345678901234567
+        );
+    }
 }
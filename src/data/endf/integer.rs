@@ -1,5 +1,8 @@
 use std::{error::Error, fmt::Display};
 
+// Maximum number of significant digits of ENDF integers.
+const ENDF_INTEGER_DIGITS: usize = 10;
+
 /// Parse ENDF integer.
 ///
 /// # Format
@@ -35,6 +38,7 @@ use std::{error::Error, fmt::Display};
 /// [`ParseEndfIntegerError`] is returned if:
 /// - `integer.is_empty()`: empty slice
 /// - `integer.len() > 11`: too long slice
+/// - `integer` has more than 10 significant digits (leading zeros excluded)
 /// - `integer` contains only sign `-` or `+`
 /// - `integer` contains invalid sign/digit
 /// - `integer` is only partially parsable
@@ -55,6 +59,38 @@ pub fn parse_endf_integer<I: AsRef<[u8]>>(integer: I) -> Result<i64, ParseEndfIn
     if integer.len() > 11 {
         return Err(ParseEndfIntegerError);
     }
+    // fast path for clean fields, general path otherwise
+    match parse_clean_integer(integer) {
+        Some(result) => result,
+        None => parse_spaced_integer(integer),
+    }
+}
+
+// Parses clean fields `' '* sign? digit+` (no interior/trailing spaces),
+// returns `None` for any other field.
+fn parse_clean_integer(integer: &[u8]) -> Option<Result<i64, ParseEndfIntegerError>> {
+    let start = integer.iter().position(|&b| b != b' ')?;
+    let (negative, digits) = match integer[start] {
+        b'-' => (true, &integer[start + 1..]),
+        b'+' => (false, &integer[start + 1..]),
+        _ => (false, &integer[start..]),
+    };
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    // -> too many significant digits (unsigned 11 columns integer)
+    if digits.iter().skip_while(|&&b| b == b'0').count() > ENDF_INTEGER_DIGITS {
+        return Some(Err(ParseEndfIntegerError));
+    }
+    // - integer.len() <= 11 => no i64 overflow (i64 max digits = 19 > 11)
+    let value = digits
+        .iter()
+        .fold(0, |value, &b| value * 10 + (b - b'0') as i64);
+    Some(Ok(if negative { -value } else { value }))
+}
+
+// Parses any field (Fortran blank interpretation mode: spaces are ignored).
+fn parse_spaced_integer(integer: &[u8]) -> Result<i64, ParseEndfIntegerError> {
     // - integer.len() <= 11 => no i64 overflow (i64 max digits = 19 > 11)
     let mut iter = integer.iter().filter(|&b| *b != b' ').peekable();
     // extract sign
@@ -77,13 +113,21 @@ pub fn parse_endf_integer<I: AsRef<[u8]>>(integer: I) -> Result<i64, ParseEndfIn
     }
     // parse digits
     let mut value = 0;
+    let mut digits = 0;
     for byte in iter {
         if byte.is_ascii_digit() {
             value = value * 10 + (byte - b'0') as i64; // no overflow
+            if value != 0 {
+                digits += 1;
+            }
         } else {
             return Err(ParseEndfIntegerError);
         }
     }
+    // -> too many significant digits (unsigned 11 columns integer)
+    if digits > ENDF_INTEGER_DIGITS {
+        return Err(ParseEndfIntegerError);
+    }
     // apply sign
     if negative {
         value = -value;
@@ -91,6 +135,32 @@ pub fn parse_endf_integer<I: AsRef<[u8]>>(integer: I) -> Result<i64, ParseEndfIn
     Ok(value)
 }
 
+/// Parse ENDF integer and convert it to `T`.
+///
+/// # Format
+///
+/// Refer to [`parse_endf_integer`] documentation for ENDF integer format.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::parse_endf_integer_as;
+/// let integer: u32 = parse_endf_integer_as("       1234").unwrap();
+/// assert_eq!(integer, 1234);
+/// assert!(parse_endf_integer_as::<u32, _>("      -1234").is_err());
+/// ```
+///
+/// # Errors
+///
+/// [`ParseEndfIntegerError`] is returned if:
+/// - parsing ENDF integer failed (see [`parse_endf_integer`])
+/// - integer can not be converted to `T`
+pub fn parse_endf_integer_as<T: TryFrom<i64>, I: AsRef<[u8]>>(
+    integer: I,
+) -> Result<T, ParseEndfIntegerError> {
+    T::try_from(parse_endf_integer(integer)?).map_err(|_| ParseEndfIntegerError)
+}
+
 /// Error returned when parsing an ENDF integer with [`parse_endf_integer`] fails.
 #[derive(Debug)]
 pub struct ParseEndfIntegerError;
@@ -123,6 +193,15 @@ mod tests {
         assert!(parse_endf_integer("            ").is_err());
     }
 
+    #[test]
+    fn too_many_digits() {
+        assert_endf_integer_eq(" 9999999999", 9999999999);
+        assert_endf_integer_eq("-9999999999", -9999999999);
+        assert_endf_integer_eq("00000000001", 1);
+        assert!(parse_endf_integer("99999999999").is_err());
+        assert!(parse_endf_integer("10000000000").is_err());
+    }
+
     #[test]
     fn sign_only() {
         assert!(parse_endf_integer("-").is_err());
@@ -228,6 +307,18 @@ mod tests {
         assert_endf_integer_eq("           ", 0);
     }
 
+    #[test]
+    fn as_unsigned() {
+        assert_eq!(
+            parse_endf_integer_as::<u32, _>("       1234").unwrap(),
+            1234
+        );
+        assert_eq!(parse_endf_integer_as::<usize, _>("          0").unwrap(), 0);
+        assert!(parse_endf_integer_as::<u32, _>("      -1234").is_err());
+        assert!(parse_endf_integer_as::<u8, _>("        256").is_err());
+        assert!(parse_endf_integer_as::<u32, _>("       12a4").is_err());
+    }
+
     #[test]
     fn space() {
         assert_endf_integer_eq(" 12 ", 12);
@@ -243,4 +334,32 @@ mod tests {
         assert_endf_integer_eq("+    1    2", 12);
         assert_endf_integer_eq("1 2 3 4 5 6", 123456)
     }
+
+    #[test]
+    fn clean_equivalence() {
+        let mut fields = vec![String::new(), "-".into(), "+".into(), "00000000000".into()];
+        for value in [0i64, 1, 7, 42, 1234, 9999999999, 1234567890] {
+            for sign in ["", "-", "+"] {
+                let digits = format!("{sign}{value}");
+                for width in digits.len()..=12 {
+                    fields.push(format!("{digits:>width$}"));
+                    fields.push(format!("{digits:0>width$}"));
+                    fields.push(format!("{digits:<width$}"));
+                }
+            }
+        }
+        fields.extend(["99999999999", "-1 2", "1-2", "12a", " - 1", "+-1"].map(String::from));
+        for field in fields {
+            let expected = parse_spaced_integer(field.as_bytes()).ok();
+            let field = field.as_bytes();
+            if field.is_empty() || field.len() > 11 {
+                assert!(parse_endf_integer(field).is_err());
+                continue;
+            }
+            if let Some(result) = parse_clean_integer(field) {
+                assert_eq!(result.ok(), expected, "{:?}", std::str::from_utf8(field));
+            }
+            assert_eq!(parse_endf_integer(field).ok(), expected);
+        }
+    }
 }
@@ -0,0 +1,561 @@
+/// Returns description of ENDF *MF* file number.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::mf_name;
+///
+/// assert_eq!(mf_name(3), Some("reaction cross sections"));
+/// assert_eq!(mf_name(99), None);
+/// ```
+///
+/// # Returns
+///
+/// - `Some(description)` if `mf` is a standard *MF* number
+/// - `None` otherwise
+///
+/// # References
+///
+/// Trkov, A., Herman, M., & Brown, D. A. (2012). *ENDF-6 formats manual*,
+/// Section 0.4.3: Data Files.
+pub fn mf_name(mf: u32) -> Option<&'static str> {
+    match mf {
+        1 => Some("general information"),
+        2 => Some("resonance parameters"),
+        3 => Some("reaction cross sections"),
+        4 => Some("angular distributions for emitted particles"),
+        5 => Some("energy distributions for emitted particles"),
+        6 => Some("energy-angle distributions for emitted particles"),
+        7 => Some("thermal neutron scattering law data"),
+        8 => Some("radioactivity and fission-product yield data"),
+        9 => Some("multiplicities for radioactive nuclide production"),
+        10 => Some("cross sections for radioactive nuclide production"),
+        12 => Some("multiplicities for photon production"),
+        13 => Some("cross sections for photon production"),
+        14 => Some("angular distributions for photon production"),
+        15 => Some("energy distributions for photon production"),
+        23 => Some("photo- or electro-atomic interaction cross sections"),
+        26 => Some("electro-atomic angle and energy distributions"),
+        27 => Some("atomic form factors or scattering functions"),
+        28 => Some("atomic relaxation data"),
+        30 => Some("covariances obtained from parameter covariances and sensitivities"),
+        31 => Some("covariances of fission multiplicities"),
+        32 => Some("covariances of resonance parameters"),
+        33 => Some("covariances of reaction cross sections"),
+        34 => Some("covariances of angular distributions"),
+        35 => Some("covariances of energy distributions"),
+        39 => Some("covariances of radionuclide production yields"),
+        40 => Some("covariances of radionuclide production cross sections"),
+        _ => None,
+    }
+}
+
+/// Returns reaction name of ENDF *MT* section number.
+///
+/// Standard reactions are named after their usual name (e.g. `"elastic"`,
+/// `"fission"`, `"radiative capture"`) and other reactions after their ENDF
+/// notation `(z,x)` where `z` is the incident particle and `x` the outgoing
+/// particles (e.g. `"(z,2n)"`, `"(z,n1)"` for inelastic scattering to the
+/// first excited state and `"(z,nc)"` to the continuum).
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::mt_name;
+///
+/// assert_eq!(mt_name(2), Some("elastic"));
+/// assert_eq!(mt_name(16), Some("(z,2n)"));
+/// assert_eq!(mt_name(999), None);
+/// ```
+///
+/// # Returns
+///
+/// - `Some(name)` if `mt` is a standard *MT* number
+/// - `None` otherwise
+///
+/// # Notes
+///
+/// Atomic subshell specific *MT* numbers (534-572) are not named.
+///
+/// # References
+///
+/// Trkov, A., Herman, M., & Brown, D. A. (2012). *ENDF-6 formats manual*,
+/// Appendix B: Definition of Reaction Types.
+pub fn mt_name(mt: u32) -> Option<&'static str> {
+    match mt {
+        1 => Some("total"),
+        2 => Some("elastic"),
+        3 => Some("nonelastic"),
+        4 => Some("(z,n)"),
+        5 => Some("(z,anything)"),
+        10 => Some("(z,continuum)"),
+        11 => Some("(z,2nd)"),
+        16 => Some("(z,2n)"),
+        17 => Some("(z,3n)"),
+        18 => Some("fission"),
+        19 => Some("first-chance fission"),
+        20 => Some("second-chance fission"),
+        21 => Some("third-chance fission"),
+        22 => Some("(z,nα)"),
+        23 => Some("(z,n3α)"),
+        24 => Some("(z,2nα)"),
+        25 => Some("(z,3nα)"),
+        27 => Some("absorption"),
+        28 => Some("(z,np)"),
+        29 => Some("(z,n2α)"),
+        30 => Some("(z,2n2α)"),
+        32 => Some("(z,nd)"),
+        33 => Some("(z,nt)"),
+        34 => Some("(z,n3He)"),
+        35 => Some("(z,nd2α)"),
+        36 => Some("(z,nt2α)"),
+        37 => Some("(z,4n)"),
+        38 => Some("fourth-chance fission"),
+        41 => Some("(z,2np)"),
+        42 => Some("(z,3np)"),
+        44 => Some("(z,n2p)"),
+        45 => Some("(z,npα)"),
+        50 => Some("(z,n0)"),
+        51 => Some("(z,n1)"),
+        52 => Some("(z,n2)"),
+        53 => Some("(z,n3)"),
+        54 => Some("(z,n4)"),
+        55 => Some("(z,n5)"),
+        56 => Some("(z,n6)"),
+        57 => Some("(z,n7)"),
+        58 => Some("(z,n8)"),
+        59 => Some("(z,n9)"),
+        60 => Some("(z,n10)"),
+        61 => Some("(z,n11)"),
+        62 => Some("(z,n12)"),
+        63 => Some("(z,n13)"),
+        64 => Some("(z,n14)"),
+        65 => Some("(z,n15)"),
+        66 => Some("(z,n16)"),
+        67 => Some("(z,n17)"),
+        68 => Some("(z,n18)"),
+        69 => Some("(z,n19)"),
+        70 => Some("(z,n20)"),
+        71 => Some("(z,n21)"),
+        72 => Some("(z,n22)"),
+        73 => Some("(z,n23)"),
+        74 => Some("(z,n24)"),
+        75 => Some("(z,n25)"),
+        76 => Some("(z,n26)"),
+        77 => Some("(z,n27)"),
+        78 => Some("(z,n28)"),
+        79 => Some("(z,n29)"),
+        80 => Some("(z,n30)"),
+        81 => Some("(z,n31)"),
+        82 => Some("(z,n32)"),
+        83 => Some("(z,n33)"),
+        84 => Some("(z,n34)"),
+        85 => Some("(z,n35)"),
+        86 => Some("(z,n36)"),
+        87 => Some("(z,n37)"),
+        88 => Some("(z,n38)"),
+        89 => Some("(z,n39)"),
+        90 => Some("(z,n40)"),
+        91 => Some("(z,nc)"),
+        101 => Some("disappearance"),
+        102 => Some("radiative capture"),
+        103 => Some("(z,p)"),
+        104 => Some("(z,d)"),
+        105 => Some("(z,t)"),
+        106 => Some("(z,3He)"),
+        107 => Some("(z,α)"),
+        108 => Some("(z,2α)"),
+        109 => Some("(z,3α)"),
+        111 => Some("(z,2p)"),
+        112 => Some("(z,pα)"),
+        113 => Some("(z,t2α)"),
+        114 => Some("(z,d2α)"),
+        115 => Some("(z,pd)"),
+        116 => Some("(z,pt)"),
+        117 => Some("(z,dα)"),
+        151 => Some("resonance parameters"),
+        152 => Some("(z,5n)"),
+        153 => Some("(z,6n)"),
+        154 => Some("(z,2nt)"),
+        155 => Some("(z,tα)"),
+        156 => Some("(z,4np)"),
+        157 => Some("(z,3nd)"),
+        158 => Some("(z,ndα)"),
+        159 => Some("(z,2npα)"),
+        160 => Some("(z,7n)"),
+        161 => Some("(z,8n)"),
+        162 => Some("(z,5np)"),
+        163 => Some("(z,6np)"),
+        164 => Some("(z,7np)"),
+        165 => Some("(z,4nα)"),
+        166 => Some("(z,5nα)"),
+        167 => Some("(z,6nα)"),
+        168 => Some("(z,7nα)"),
+        169 => Some("(z,4nd)"),
+        170 => Some("(z,5nd)"),
+        171 => Some("(z,6nd)"),
+        172 => Some("(z,3nt)"),
+        173 => Some("(z,4nt)"),
+        174 => Some("(z,5nt)"),
+        175 => Some("(z,6nt)"),
+        176 => Some("(z,2n3He)"),
+        177 => Some("(z,3n3He)"),
+        178 => Some("(z,4n3He)"),
+        179 => Some("(z,3n2p)"),
+        180 => Some("(z,3n2α)"),
+        181 => Some("(z,3npα)"),
+        182 => Some("(z,dt)"),
+        183 => Some("(z,npd)"),
+        184 => Some("(z,npt)"),
+        185 => Some("(z,ndt)"),
+        186 => Some("(z,np3He)"),
+        187 => Some("(z,nd3He)"),
+        188 => Some("(z,nt3He)"),
+        189 => Some("(z,ntα)"),
+        190 => Some("(z,2n2p)"),
+        191 => Some("(z,p3He)"),
+        192 => Some("(z,d3He)"),
+        193 => Some("(z,3Heα)"),
+        194 => Some("(z,4n2p)"),
+        195 => Some("(z,4n2α)"),
+        196 => Some("(z,4npα)"),
+        197 => Some("(z,3p)"),
+        198 => Some("(z,n3p)"),
+        199 => Some("(z,3n2pα)"),
+        200 => Some("(z,5n2p)"),
+        201 => Some("neutron production"),
+        202 => Some("photon production"),
+        203 => Some("proton production"),
+        204 => Some("deuteron production"),
+        205 => Some("triton production"),
+        206 => Some("helium-3 production"),
+        207 => Some("alpha production"),
+        251 => Some("average cosine of scattering angle"),
+        252 => Some("average logarithmic energy decrement"),
+        253 => Some("average ξ² divided by 2ξ"),
+        451 => Some("descriptive data"),
+        452 => Some("total fission neutron multiplicity"),
+        454 => Some("independent fission product yields"),
+        455 => Some("delayed fission neutron multiplicity"),
+        456 => Some("prompt fission neutron multiplicity"),
+        457 => Some("radioactive decay data"),
+        458 => Some("fission energy release"),
+        459 => Some("cumulative fission product yields"),
+        460 => Some("delayed fission photons"),
+        500 => Some("total charged-particle stopping power"),
+        501 => Some("photon total"),
+        502 => Some("photon coherent scattering"),
+        504 => Some("photon incoherent scattering"),
+        505 => Some("imaginary scattering factor"),
+        506 => Some("real scattering factor"),
+        515 => Some("pair production, electron field"),
+        516 => Some("pair production"),
+        517 => Some("pair production, nuclear field"),
+        522 => Some("photoelectric absorption"),
+        523 => Some("photo-excitation"),
+        526 => Some("electro-atomic elastic scattering"),
+        527 => Some("electro-atomic bremsstrahlung"),
+        528 => Some("electro-atomic excitation"),
+        533 => Some("atomic relaxation data"),
+        600 => Some("(z,p0)"),
+        601 => Some("(z,p1)"),
+        602 => Some("(z,p2)"),
+        603 => Some("(z,p3)"),
+        604 => Some("(z,p4)"),
+        605 => Some("(z,p5)"),
+        606 => Some("(z,p6)"),
+        607 => Some("(z,p7)"),
+        608 => Some("(z,p8)"),
+        609 => Some("(z,p9)"),
+        610 => Some("(z,p10)"),
+        611 => Some("(z,p11)"),
+        612 => Some("(z,p12)"),
+        613 => Some("(z,p13)"),
+        614 => Some("(z,p14)"),
+        615 => Some("(z,p15)"),
+        616 => Some("(z,p16)"),
+        617 => Some("(z,p17)"),
+        618 => Some("(z,p18)"),
+        619 => Some("(z,p19)"),
+        620 => Some("(z,p20)"),
+        621 => Some("(z,p21)"),
+        622 => Some("(z,p22)"),
+        623 => Some("(z,p23)"),
+        624 => Some("(z,p24)"),
+        625 => Some("(z,p25)"),
+        626 => Some("(z,p26)"),
+        627 => Some("(z,p27)"),
+        628 => Some("(z,p28)"),
+        629 => Some("(z,p29)"),
+        630 => Some("(z,p30)"),
+        631 => Some("(z,p31)"),
+        632 => Some("(z,p32)"),
+        633 => Some("(z,p33)"),
+        634 => Some("(z,p34)"),
+        635 => Some("(z,p35)"),
+        636 => Some("(z,p36)"),
+        637 => Some("(z,p37)"),
+        638 => Some("(z,p38)"),
+        639 => Some("(z,p39)"),
+        640 => Some("(z,p40)"),
+        641 => Some("(z,p41)"),
+        642 => Some("(z,p42)"),
+        643 => Some("(z,p43)"),
+        644 => Some("(z,p44)"),
+        645 => Some("(z,p45)"),
+        646 => Some("(z,p46)"),
+        647 => Some("(z,p47)"),
+        648 => Some("(z,p48)"),
+        649 => Some("(z,pc)"),
+        650 => Some("(z,d0)"),
+        651 => Some("(z,d1)"),
+        652 => Some("(z,d2)"),
+        653 => Some("(z,d3)"),
+        654 => Some("(z,d4)"),
+        655 => Some("(z,d5)"),
+        656 => Some("(z,d6)"),
+        657 => Some("(z,d7)"),
+        658 => Some("(z,d8)"),
+        659 => Some("(z,d9)"),
+        660 => Some("(z,d10)"),
+        661 => Some("(z,d11)"),
+        662 => Some("(z,d12)"),
+        663 => Some("(z,d13)"),
+        664 => Some("(z,d14)"),
+        665 => Some("(z,d15)"),
+        666 => Some("(z,d16)"),
+        667 => Some("(z,d17)"),
+        668 => Some("(z,d18)"),
+        669 => Some("(z,d19)"),
+        670 => Some("(z,d20)"),
+        671 => Some("(z,d21)"),
+        672 => Some("(z,d22)"),
+        673 => Some("(z,d23)"),
+        674 => Some("(z,d24)"),
+        675 => Some("(z,d25)"),
+        676 => Some("(z,d26)"),
+        677 => Some("(z,d27)"),
+        678 => Some("(z,d28)"),
+        679 => Some("(z,d29)"),
+        680 => Some("(z,d30)"),
+        681 => Some("(z,d31)"),
+        682 => Some("(z,d32)"),
+        683 => Some("(z,d33)"),
+        684 => Some("(z,d34)"),
+        685 => Some("(z,d35)"),
+        686 => Some("(z,d36)"),
+        687 => Some("(z,d37)"),
+        688 => Some("(z,d38)"),
+        689 => Some("(z,d39)"),
+        690 => Some("(z,d40)"),
+        691 => Some("(z,d41)"),
+        692 => Some("(z,d42)"),
+        693 => Some("(z,d43)"),
+        694 => Some("(z,d44)"),
+        695 => Some("(z,d45)"),
+        696 => Some("(z,d46)"),
+        697 => Some("(z,d47)"),
+        698 => Some("(z,d48)"),
+        699 => Some("(z,dc)"),
+        700 => Some("(z,t0)"),
+        701 => Some("(z,t1)"),
+        702 => Some("(z,t2)"),
+        703 => Some("(z,t3)"),
+        704 => Some("(z,t4)"),
+        705 => Some("(z,t5)"),
+        706 => Some("(z,t6)"),
+        707 => Some("(z,t7)"),
+        708 => Some("(z,t8)"),
+        709 => Some("(z,t9)"),
+        710 => Some("(z,t10)"),
+        711 => Some("(z,t11)"),
+        712 => Some("(z,t12)"),
+        713 => Some("(z,t13)"),
+        714 => Some("(z,t14)"),
+        715 => Some("(z,t15)"),
+        716 => Some("(z,t16)"),
+        717 => Some("(z,t17)"),
+        718 => Some("(z,t18)"),
+        719 => Some("(z,t19)"),
+        720 => Some("(z,t20)"),
+        721 => Some("(z,t21)"),
+        722 => Some("(z,t22)"),
+        723 => Some("(z,t23)"),
+        724 => Some("(z,t24)"),
+        725 => Some("(z,t25)"),
+        726 => Some("(z,t26)"),
+        727 => Some("(z,t27)"),
+        728 => Some("(z,t28)"),
+        729 => Some("(z,t29)"),
+        730 => Some("(z,t30)"),
+        731 => Some("(z,t31)"),
+        732 => Some("(z,t32)"),
+        733 => Some("(z,t33)"),
+        734 => Some("(z,t34)"),
+        735 => Some("(z,t35)"),
+        736 => Some("(z,t36)"),
+        737 => Some("(z,t37)"),
+        738 => Some("(z,t38)"),
+        739 => Some("(z,t39)"),
+        740 => Some("(z,t40)"),
+        741 => Some("(z,t41)"),
+        742 => Some("(z,t42)"),
+        743 => Some("(z,t43)"),
+        744 => Some("(z,t44)"),
+        745 => Some("(z,t45)"),
+        746 => Some("(z,t46)"),
+        747 => Some("(z,t47)"),
+        748 => Some("(z,t48)"),
+        749 => Some("(z,tc)"),
+        750 => Some("(z,3He0)"),
+        751 => Some("(z,3He1)"),
+        752 => Some("(z,3He2)"),
+        753 => Some("(z,3He3)"),
+        754 => Some("(z,3He4)"),
+        755 => Some("(z,3He5)"),
+        756 => Some("(z,3He6)"),
+        757 => Some("(z,3He7)"),
+        758 => Some("(z,3He8)"),
+        759 => Some("(z,3He9)"),
+        760 => Some("(z,3He10)"),
+        761 => Some("(z,3He11)"),
+        762 => Some("(z,3He12)"),
+        763 => Some("(z,3He13)"),
+        764 => Some("(z,3He14)"),
+        765 => Some("(z,3He15)"),
+        766 => Some("(z,3He16)"),
+        767 => Some("(z,3He17)"),
+        768 => Some("(z,3He18)"),
+        769 => Some("(z,3He19)"),
+        770 => Some("(z,3He20)"),
+        771 => Some("(z,3He21)"),
+        772 => Some("(z,3He22)"),
+        773 => Some("(z,3He23)"),
+        774 => Some("(z,3He24)"),
+        775 => Some("(z,3He25)"),
+        776 => Some("(z,3He26)"),
+        777 => Some("(z,3He27)"),
+        778 => Some("(z,3He28)"),
+        779 => Some("(z,3He29)"),
+        780 => Some("(z,3He30)"),
+        781 => Some("(z,3He31)"),
+        782 => Some("(z,3He32)"),
+        783 => Some("(z,3He33)"),
+        784 => Some("(z,3He34)"),
+        785 => Some("(z,3He35)"),
+        786 => Some("(z,3He36)"),
+        787 => Some("(z,3He37)"),
+        788 => Some("(z,3He38)"),
+        789 => Some("(z,3He39)"),
+        790 => Some("(z,3He40)"),
+        791 => Some("(z,3He41)"),
+        792 => Some("(z,3He42)"),
+        793 => Some("(z,3He43)"),
+        794 => Some("(z,3He44)"),
+        795 => Some("(z,3He45)"),
+        796 => Some("(z,3He46)"),
+        797 => Some("(z,3He47)"),
+        798 => Some("(z,3He48)"),
+        799 => Some("(z,3Hec)"),
+        800 => Some("(z,α0)"),
+        801 => Some("(z,α1)"),
+        802 => Some("(z,α2)"),
+        803 => Some("(z,α3)"),
+        804 => Some("(z,α4)"),
+        805 => Some("(z,α5)"),
+        806 => Some("(z,α6)"),
+        807 => Some("(z,α7)"),
+        808 => Some("(z,α8)"),
+        809 => Some("(z,α9)"),
+        810 => Some("(z,α10)"),
+        811 => Some("(z,α11)"),
+        812 => Some("(z,α12)"),
+        813 => Some("(z,α13)"),
+        814 => Some("(z,α14)"),
+        815 => Some("(z,α15)"),
+        816 => Some("(z,α16)"),
+        817 => Some("(z,α17)"),
+        818 => Some("(z,α18)"),
+        819 => Some("(z,α19)"),
+        820 => Some("(z,α20)"),
+        821 => Some("(z,α21)"),
+        822 => Some("(z,α22)"),
+        823 => Some("(z,α23)"),
+        824 => Some("(z,α24)"),
+        825 => Some("(z,α25)"),
+        826 => Some("(z,α26)"),
+        827 => Some("(z,α27)"),
+        828 => Some("(z,α28)"),
+        829 => Some("(z,α29)"),
+        830 => Some("(z,α30)"),
+        831 => Some("(z,α31)"),
+        832 => Some("(z,α32)"),
+        833 => Some("(z,α33)"),
+        834 => Some("(z,α34)"),
+        835 => Some("(z,α35)"),
+        836 => Some("(z,α36)"),
+        837 => Some("(z,α37)"),
+        838 => Some("(z,α38)"),
+        839 => Some("(z,α39)"),
+        840 => Some("(z,α40)"),
+        841 => Some("(z,α41)"),
+        842 => Some("(z,α42)"),
+        843 => Some("(z,α43)"),
+        844 => Some("(z,α44)"),
+        845 => Some("(z,α45)"),
+        846 => Some("(z,α46)"),
+        847 => Some("(z,α47)"),
+        848 => Some("(z,α48)"),
+        849 => Some("(z,αc)"),
+        875 => Some("(z,2n0)"),
+        876 => Some("(z,2n1)"),
+        877 => Some("(z,2n2)"),
+        878 => Some("(z,2n3)"),
+        879 => Some("(z,2n4)"),
+        880 => Some("(z,2n5)"),
+        881 => Some("(z,2n6)"),
+        882 => Some("(z,2n7)"),
+        883 => Some("(z,2n8)"),
+        884 => Some("(z,2n9)"),
+        885 => Some("(z,2n10)"),
+        886 => Some("(z,2n11)"),
+        887 => Some("(z,2n12)"),
+        888 => Some("(z,2n13)"),
+        889 => Some("(z,2n14)"),
+        890 => Some("(z,2n15)"),
+        891 => Some("(z,2nc)"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mf() {
+        assert_eq!(mf_name(1), Some("general information"));
+        assert_eq!(mf_name(3), Some("reaction cross sections"));
+        assert_eq!(
+            mf_name(6),
+            Some("energy-angle distributions for emitted particles")
+        );
+        assert_eq!(mf_name(11), None);
+        assert_eq!(mf_name(99), None);
+    }
+
+    #[test]
+    fn mt() {
+        assert_eq!(mt_name(1), Some("total"));
+        assert_eq!(mt_name(2), Some("elastic"));
+        assert_eq!(mt_name(18), Some("fission"));
+        assert_eq!(mt_name(51), Some("(z,n1)"));
+        assert_eq!(mt_name(91), Some("(z,nc)"));
+        assert_eq!(mt_name(102), Some("radiative capture"));
+        assert_eq!(mt_name(107), Some("(z,α)"));
+        assert_eq!(mt_name(649), Some("(z,pc)"));
+        assert_eq!(mt_name(849), Some("(z,αc)"));
+        assert_eq!(mt_name(0), None);
+        assert_eq!(mt_name(999), None);
+    }
+}
@@ -1,16 +1,32 @@
 use std::io::BufRead;
+#[cfg(feature = "flate2")]
+use std::io::{BufReader, Read};
+
+#[cfg(feature = "flate2")]
+use flate2::read::GzDecoder;
 
 use super::{
-    parse_endf_integer, parse_float, parse_integer, Cont, EndfError, Intg, List, Tab1, Tab2, Text,
+    list_from, parse_cont, parse_control_numbers, parse_endf_integer, parse_text, tab1_from,
+    tab1_into, tab2_from, Cont, DirEntry, EndfError, Head, Intg, List, Tab1, Tab2, Text,
 };
 
 // Maximum endf line length: 80 chars + optional `\r` + `\n`.
 const ENDF_MAX_LINE_LENGTH: usize = 82;
 
 /// Reader specialized for ENDF format files.
+///
+/// Errors returned by reading methods (except [`EndfError::EndOfFile`]) are
+/// wrapped in [`EndfError::At`] with the number of the line being read.
+///
+/// Sequence numbers (NS) are checked on demand, see
+/// [`EndfReader::with_validation`].
 #[derive(Debug)]
 pub struct EndfReader<B: BufRead> {
     buf: B,
+    line: usize,
+    validation: bool,
+    // Control numbers `(MAT, MF, MT, NS)` of last validated record.
+    previous: Option<(i32, u32, u32, u32)>,
 }
 
 impl<B: BufRead> EndfReader<B> {
@@ -29,7 +45,63 @@ impl<B: BufRead> EndfReader<B> {
     /// let endf_reader = EndfReader::new(buf_reader);
     /// ```
     pub fn new(buf: B) -> Self {
-        Self { buf }
+        Self {
+            buf,
+            line: 0,
+            validation: false,
+            previous: None,
+        }
+    }
+
+    /// Enables validation of sequence numbers (NS) of read records.
+    ///
+    /// Within a section, the sequence number of each record must increment
+    /// by 1 from the sequence number of the previous record, starting at 1 on
+    /// the first record of the section. Terminator records (`MT=0`), as well
+    /// as records without control or sequence numbers, are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let file = File::open("path/to/file.endf")?;
+    /// let mut reader = EndfReader::new(BufReader::new(file)).with_validation();
+    /// let list = reader.read_list()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Once enabled, reading methods return [`EndfError::Data`] (wrapped in
+    /// [`EndfError::At`]) on out of sequence records.
+    pub fn with_validation(mut self) -> Self {
+        self.validation = true;
+        self
+    }
+
+    /// Returns the number of lines read so far.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// let list = reader.read_list()?;
+    /// println!("parsed up to line {}", reader.line_number());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn line_number(&self) -> usize {
+        self.line
     }
 
     /// Reads a line from the `EndfReader`.
@@ -48,12 +120,125 @@ impl<B: BufRead> EndfReader<B> {
     /// # }
     /// ```
     pub fn read_line(&mut self) -> Result<Vec<u8>, EndfError> {
-        let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
-        match self.buf.read_until(b'\n', &mut buf) {
-            Ok(0) => Err(EndfError::EndOfFile),
-            Err(error) => Err(error.into()),
-            Ok(_) => Ok(buf),
-        }
+        self.located(|reader| {
+            let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
+            match reader.buf.read_until(b'\n', &mut buf) {
+                Ok(0) => Err(EndfError::EndOfFile),
+                Err(error) => Err(error.into()),
+                Ok(_) => {
+                    reader.line += 1;
+                    reader.validate(&buf)?;
+                    Ok(buf)
+                }
+            }
+        })
+    }
+
+    /// Converts the `EndfReader` into an iterator over its lines.
+    ///
+    /// Iteration ends cleanly at end of file: [`EndfError::EndOfFile`] is
+    /// **not** yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// for line in reader.lines_iter() {
+    ///     let line = line?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lines_iter(mut self) -> impl Iterator<Item = Result<Vec<u8>, EndfError>> {
+        std::iter::from_fn(move || match self.read_line() {
+            Err(EndfError::EndOfFile) => None,
+            result => Some(result),
+        })
+    }
+
+    /// Reads a line from the `EndfReader` and returns its control numbers
+    /// `(MAT, MF, MT, NS)`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::{is_tend, EndfReader};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// loop {
+    ///     let (mat, mf, mt, _) = reader.read_control()?;
+    ///     if is_tend(mat, mf, mt) {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - malformed/invalid control numbers
+    /// - line shorter than 75 columns
+    pub fn read_control(&mut self) -> Result<(i32, u32, u32, Option<u32>), EndfError> {
+        self.located(|reader| {
+            let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
+            reader.read_record(&mut buf)?;
+            parse_control_numbers(&buf)?.ok_or(EndfError::Format)
+        })
+    }
+
+    /// Skips lines until the end of current section, returning the number of
+    /// skipped lines.
+    ///
+    /// Lines are discarded (without parsing data columns) up to and including
+    /// the next record with `MT=0` (**SEND** record, or **FEND**/**MEND**/
+    /// **TEND** record if reader is not within a section). Lines without
+    /// control numbers are skipped as well.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// // skip MF=1/MT=451 section, then read first record of next section
+    /// reader.skip_section()?;
+    /// let (mat, mf, mt, _) = reader.read_control()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - malformed/invalid control numbers
+    /// - end of file is reached before a `MT=0` record
+    pub fn skip_section(&mut self) -> Result<usize, EndfError> {
+        self.located(|reader| {
+            let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
+            let mut skipped = 0;
+            loop {
+                reader.read_record(&mut buf)?;
+                skipped += 1;
+                if let Some((_, _, 0, _)) = parse_control_numbers(&buf)? {
+                    return Ok(skipped);
+                }
+            }
+        })
     }
 
     /// Reads a **CONT** record from the `EndfReader`.
@@ -77,27 +262,103 @@ impl<B: BufRead> EndfReader<B> {
     /// Errors if:
     /// - I/O error occurs
     /// - malformed/invalid data
+    /// - line shorter than 66 columns
     pub fn read_cont(&mut self) -> Result<Cont, EndfError> {
-        let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
-        match self.buf.read_until(b'\n', &mut buf) {
-            Ok(0) => Err(EndfError::EndOfFile),
-            Err(error) => Err(error.into()),
-            Ok(_) => {
-                let c1 = parse_float(&buf, 1)?;
-                let c2 = parse_float(&buf, 2)?;
-                let l1 = parse_integer(&buf, 3)?;
-                let l2 = parse_integer(&buf, 4)?;
-                let n1 = parse_integer(&buf, 5)?;
-                let n2 = parse_integer(&buf, 6)?;
-                Ok(Cont(c1, c2, l1, l2, n1, n2))
-            }
-        }
+        self.located(|reader| {
+            let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
+            reader.read_record(&mut buf)?;
+            parse_cont(&buf)
+        })
+    }
+
+    /// Reads a **HEAD** record from the `EndfReader`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::{EndfReader, Head};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// let Head(za, awr, l1, l2, n1, n2) = reader.read_head()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - malformed/invalid data
+    /// - line shorter than 66 columns
+    pub fn read_head(&mut self) -> Result<Head, EndfError> {
+        let Cont(za, awr, l1, l2, n1, n2) = self.read_cont()?;
+        Ok(Head(za, awr, l1, l2, n1, n2))
+    }
+
+    /// Reads a directory entry of **MF=1/MT=451** section from the `EndfReader`.
+    ///
+    /// # Format
+    ///
+    /// Directory entries are **CONT** records with blank `C1`/`C2` fields and
+    /// `L1`/`L2`/`N1`/`N2` fields holding `MF`/`MT`/`NC`/`MOD`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// let entry = reader.read_dir()?;
+    /// println!("MF={} MT={} ({} lines)", entry.mf, entry.mt, entry.lines);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - malformed/invalid data
+    /// - negative `MF`/`MT`/`NC`/`MOD` fields
+    /// - line shorter than 66 columns
+    pub fn read_dir(&mut self) -> Result<DirEntry, EndfError> {
+        let Cont(_, _, mf, mt, nc, modification) = self.read_cont()?;
+        self.located(|_| {
+            let field = |value: i64| u32::try_from(value).map_err(|_| EndfError::Data);
+            Ok(DirEntry {
+                mf: field(mf)?,
+                mt: field(mt)?,
+                lines: field(nc)?,
+                modification: field(modification)?,
+            })
+        })
     }
 
     /// Reads a **INTG** record from the `EndfReader`.
     ///
     /// `ndigit` denotes the number of digits for values.
     ///
+    /// # Format
+    ///
+    /// Values are signed integers right-aligned in `ndigit + 1` columns wide
+    /// fields, the number of values per record depending on `ndigit`:
+    ///
+    /// | NDIGIT | Format                            | Values |
+    /// |--------|-----------------------------------|--------|
+    /// | 2      | `(I5, I5, 1X, 18I3, 1X)`          | 18     |
+    /// | 3      | `(I5, I5, 1X, 13I4, 3X)`          | 13     |
+    /// | 4      | `(I5, I5, 1X, 11I5)`              | 11     |
+    /// | 5      | `(I5, I5, 1X, 9I6, 1X)`           | 9      |
+    /// | 6      | `(I5, I5, 8I7)`                   | 8      |
+    ///
+    /// Blank fields are read as `0`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -117,6 +378,7 @@ impl<B: BufRead> EndfReader<B> {
     /// Errors if:
     /// - I/O error occurs
     /// - malformed/invalid data
+    /// - line shorter than 66 columns
     ///
     /// # Panics
     ///
@@ -124,42 +386,29 @@ impl<B: BufRead> EndfReader<B> {
     pub fn read_intg(&mut self, ndigit: usize) -> Result<Intg, EndfError> {
         assert!(ndigit >= 2);
         assert!(ndigit <= 6);
-        let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
-        match self.buf.read_until(b'\n', &mut buf) {
-            Ok(0) => Err(EndfError::EndOfFile),
-            Err(error) => Err(error.into()),
-            Ok(_) => {
-                let ii = match buf.get(0..5) {
-                    Some(slice) => match parse_endf_integer(slice) {
-                        Ok(integer) => integer,
-                        Err(_) => return Err(EndfError::Data),
-                    },
-                    None => return Err(EndfError::Format),
-                };
-                let jj = match buf.get(5..10) {
-                    Some(slice) => match parse_endf_integer(slice) {
-                        Ok(integer) => integer,
-                        Err(_) => return Err(EndfError::Data),
-                    },
-                    None => return Err(EndfError::Format),
-                };
-                let mut kij = Vec::new();
-                let mut ptr = if ndigit <= 5 { 11 } else { 10 };
-                loop {
-                    if ptr + ndigit + 1 > 66 {
-                        break;
-                    }
-                    let slice = &buf[ptr..ptr + ndigit + 1];
-                    let value = match parse_endf_integer(slice) {
-                        Ok(value) => value,
-                        Err(_) => return Err(EndfError::Data),
-                    };
-                    kij.push(value);
-                    ptr += ndigit + 1;
-                }
-                Ok(Intg(ii, jj, kij))
-            }
-        }
+        self.located(|reader| {
+            let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
+            reader.read_record(&mut buf)?;
+            let field = |start: usize, width: usize| match buf.get(start..start + width) {
+                Some(slice) => parse_endf_integer(slice).map_err(|_| EndfError::Data),
+                None => Err(EndfError::Format),
+            };
+            let ii = field(0, 5)?;
+            let jj = field(5, 5)?;
+            // `(start, count)` of values (1X separator except for NDIGIT=6)
+            let (start, count) = match ndigit {
+                2 => (11, 18),
+                3 => (11, 13),
+                4 => (11, 11),
+                5 => (11, 9),
+                _ => (10, 8),
+            };
+            let width = ndigit + 1;
+            let kij = (0..count)
+                .map(|i| field(start + i * width, width))
+                .collect::<Result<_, _>>()?;
+            Ok(Intg(ii, jj, kij))
+        })
     }
 
     /// Reads a **LIST** record from the `EndfReader`.
@@ -183,42 +432,9 @@ impl<B: BufRead> EndfReader<B> {
     /// Errors if:
     /// - I/O error occurs
     /// - malformed/invalid data
+    /// - line shorter than 66 columns
     pub fn read_list(&mut self) -> Result<List, EndfError> {
-        let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
-        match self.buf.read_until(b'\n', &mut buf) {
-            Ok(0) => Err(EndfError::EndOfFile),
-            Err(error) => Err(error.into()),
-            Ok(_) => {
-                let c1 = parse_float(&buf, 1)?;
-                let c2 = parse_float(&buf, 2)?;
-                let l1 = parse_integer(&buf, 3)?;
-                let l2 = parse_integer(&buf, 4)?;
-                let npl = parse_integer(&buf, 5)?;
-                let n2 = parse_integer(&buf, 6)?;
-                let npl: usize = match npl.try_into() {
-                    Ok(npl) => npl,
-                    Err(_) => return Err(EndfError::Data),
-                };
-                let mut b = Vec::with_capacity(npl);
-                while b.len() < npl {
-                    buf.clear();
-                    match self.buf.read_until(b'\n', &mut buf) {
-                        Ok(0) => return Err(EndfError::EndOfFile),
-                        Err(error) => return Err(error.into()),
-                        Ok(_) => {
-                            for col in 0..6 {
-                                if b.len() == npl {
-                                    break;
-                                }
-                                let float = parse_float(&buf, col + 1)?;
-                                b.push(float);
-                            }
-                        }
-                    }
-                }
-                Ok(List(c1, c2, l1, l2, npl, n2, b))
-            }
-        }
+        self.located(|reader| list_from(|buf| reader.read_record(buf)))
     }
 
     /// Reads a **TAB1** record from the `EndfReader`.
@@ -242,73 +458,54 @@ impl<B: BufRead> EndfReader<B> {
     /// Errors if:
     /// - I/O error occurs
     /// - malformed/invalid data
+    /// - line shorter than 66 columns
     pub fn read_tab1(&mut self) -> Result<Tab1, EndfError> {
-        let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
-        match self.buf.read_until(b'\n', &mut buf) {
-            Ok(0) => Err(EndfError::EndOfFile),
-            Err(error) => Err(error.into()),
-            Ok(_) => {
-                let c1 = parse_float(&buf, 1)?;
-                let c2 = parse_float(&buf, 2)?;
-                let l1 = parse_integer(&buf, 3)?;
-                let l2 = parse_integer(&buf, 4)?;
-                let nr = parse_integer(&buf, 5)?;
-                let np = parse_integer(&buf, 6)?;
-                let nr: usize = match nr.try_into() {
-                    Ok(nr) => nr,
-                    Err(_) => return Err(EndfError::Data),
-                };
-                let np: usize = match np.try_into() {
-                    Ok(np) => np,
-                    Err(_) => return Err(EndfError::Data),
-                };
-                let mut int = Vec::with_capacity(nr);
-                while int.len() < nr {
-                    buf.clear();
-                    match self.buf.read_until(b'\n', &mut buf) {
-                        Ok(0) => return Err(EndfError::EndOfFile),
-                        Err(error) => return Err(error.into()),
-                        Ok(_) => {
-                            for col in 0..3 {
-                                if int.len() == nr {
-                                    break;
-                                }
-                                let nbt = parse_integer(&buf, 2 * col + 1)?;
-                                let nbt: u32 = match nbt.try_into() {
-                                    Ok(nbt) => nbt,
-                                    Err(_) => return Err(EndfError::Data),
-                                };
-                                let scheme = parse_integer(&buf, 2 * col + 2)?;
-                                let scheme: usize = match scheme.try_into() {
-                                    Ok(scheme) => scheme,
-                                    Err(_) => return Err(EndfError::Data),
-                                };
-                                int.push((nbt, scheme));
-                            }
-                        }
-                    }
-                }
-                let mut tab = Vec::with_capacity(np);
-                while tab.len() < np {
-                    buf.clear();
-                    match self.buf.read_until(b'\n', &mut buf) {
-                        Ok(0) => return Err(EndfError::EndOfFile),
-                        Err(error) => return Err(error.into()),
-                        Ok(_) => {
-                            for col in 0..3 {
-                                if tab.len() == np {
-                                    break;
-                                }
-                                let x = parse_float(&buf, 2 * col + 1)?;
-                                let y = parse_float(&buf, 2 * col + 2)?;
-                                tab.push((x, y));
-                            }
-                        }
-                    }
-                }
-                Ok(Tab1(c1, c2, l1, l2, nr, np, int, tab))
-            }
-        }
+        self.located(|reader| tab1_from(|buf| reader.read_record(buf)))
+    }
+
+    /// Reads a **TAB1** record from the `EndfReader` into caller's buffers.
+    ///
+    /// Same as [`EndfReader::read_tab1`], except that interpolation regions
+    /// and points are written into `int` and `tab` (cleared first) instead
+    /// of newly allocated vectors, so that buffers can be reused across
+    /// records.
+    ///
+    /// # Returns
+    ///
+    /// `(C1, C2, L1, L2)` fields of the record (`NR` and `NP` are the
+    /// lengths of `int` and `tab`).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// let (mut int, mut tab) = (Vec::new(), Vec::new());
+    /// while let Ok((c1, c2, l1, l2)) = reader.read_tab1_into(&mut int, &mut tab) {
+    ///     println!("{c1} {c2} {l1} {l2}: {} points", tab.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - malformed/invalid data
+    /// - line shorter than 66 columns
+    ///
+    /// Content of `int` and `tab` is unspecified on error.
+    pub fn read_tab1_into(
+        &mut self,
+        int: &mut Vec<(u32, usize)>,
+        tab: &mut Vec<(f64, f64)>,
+    ) -> Result<(f64, f64, i64, i64), EndfError> {
+        self.located(|reader| tab1_into(|buf| reader.read_record(buf), int, tab))
     }
 
     /// Reads a **TAB2** record from the `EndfReader`.
@@ -332,55 +529,54 @@ impl<B: BufRead> EndfReader<B> {
     /// Errors if:
     /// - I/O error occurs
     /// - malformed/invalid data
+    /// - line shorter than 66 columns
     pub fn read_tab2(&mut self) -> Result<Tab2, EndfError> {
-        let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
-        match self.buf.read_until(b'\n', &mut buf) {
-            Ok(0) => Err(EndfError::EndOfFile),
-            Err(error) => Err(error.into()),
-            Ok(_) => {
-                let c1 = parse_float(&buf, 1)?;
-                let c2 = parse_float(&buf, 2)?;
-                let l1 = parse_integer(&buf, 3)?;
-                let l2 = parse_integer(&buf, 4)?;
-                let nr = parse_integer(&buf, 5)?;
-                let nz = parse_integer(&buf, 6)?;
-                let nr: usize = match nr.try_into() {
-                    Ok(nr) => nr,
-                    Err(_) => return Err(EndfError::Data),
-                };
-                let nz: usize = match nz.try_into() {
-                    Ok(nz) => nz,
-                    Err(_) => return Err(EndfError::Data),
-                };
-                let mut int = Vec::with_capacity(nr);
-                while int.len() < nr {
-                    buf.clear();
-                    match self.buf.read_until(b'\n', &mut buf) {
-                        Ok(0) => return Err(EndfError::EndOfFile),
-                        Err(error) => return Err(error.into()),
-                        Ok(_) => {
-                            for col in 0..3 {
-                                if int.len() == nr {
-                                    break;
-                                }
-                                let nbt = parse_integer(&buf, 2 * col + 1)?;
-                                let nbt: u32 = match nbt.try_into() {
-                                    Ok(nbt) => nbt,
-                                    Err(_) => return Err(EndfError::Data),
-                                };
-                                let scheme = parse_integer(&buf, 2 * col + 2)?;
-                                let scheme: usize = match scheme.try_into() {
-                                    Ok(scheme) => scheme,
-                                    Err(_) => return Err(EndfError::Data),
-                                };
-                                int.push((nbt, scheme));
-                            }
-                        }
-                    }
-                }
-                Ok(Tab2(c1, c2, l1, l2, nr, nz, int))
-            }
-        }
+        self.located(|reader| tab2_from(|buf| reader.read_record(buf)))
+    }
+
+    /// Reads a **TAB2** record and its **TAB1** records from the `EndfReader`.
+    ///
+    /// # Format
+    ///
+    /// Two-dimensional functions `y(x, z)` are stored as a **TAB2** record
+    /// holding the interpolation scheme along `z`, followed by `NZ` **TAB1**
+    /// records holding `y(x)` for each `z` value (given in their `C2` field):
+    ///
+    /// ```text
+    /// [MAT, MF, MT/ C1, C2, L1, L2, NR, NZ/ Z_int]TAB2
+    /// [MAT, MF, MT/ C1, Z(1), L1, L2, NR, NP/ X_int/ Y(X)]TAB1
+    /// ...
+    /// [MAT, MF, MT/ C1, Z(NZ), L1, L2, NR, NP/ X_int/ Y(X)]TAB1
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// let (tab2, tab1s) = reader.read_tab2_tab1()?;
+    /// assert_eq!(tab1s.len(), tab2.nz());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - malformed/invalid data
+    /// - line shorter than 66 columns
+    /// - end of file is reached before `NZ` **TAB1** records are read
+    pub fn read_tab2_tab1(&mut self) -> Result<(Tab2, Vec<Tab1>), EndfError> {
+        let tab2 = self.read_tab2()?;
+        let tab1s = (0..tab2.nz())
+            .map(|_| self.read_tab1())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((tab2, tab1s))
     }
 
     /// Reads a **TEXT** record from the `EndfReader`.
@@ -403,19 +599,161 @@ impl<B: BufRead> EndfReader<B> {
     ///
     /// Errors if:
     /// - I/O error occurs
-    /// - malformed/invalid data
+    /// - non ASCII text ([`EndfError::Encoding`])
+    /// - line shorter than 66 columns
     pub fn read_text(&mut self) -> Result<Text, EndfError> {
-        let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
-        match self.buf.read_until(b'\n', &mut buf) {
+        self.located(|reader| {
+            let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
+            reader.read_record(&mut buf)?;
+            parse_text(&buf)
+        })
+    }
+
+    /// Reads the descriptive text of a **MF=1/MT=451** section from the `EndfReader`.
+    ///
+    /// # Format
+    ///
+    /// The `EndfReader` must be positioned on the first **TEXT** record of
+    /// the description, i.e. after the header records of the section (a
+    /// **HEAD** record followed by three **CONT** records in ENDF-6 format):
+    ///
+    /// ```text
+    /// [MAT, 1, 451/ ZA, AWR, LRP, LFI, NLIB, NMOD]HEAD
+    /// [MAT, 1, 451/ ELIS, STA, LIS, LISO, 0, NFOR]CONT
+    /// [MAT, 1, 451/ AWI, EMAX, LREL, 0, NSUB, NVER]CONT
+    /// [MAT, 1, 451/ TEMP, 0.0, LDRV, 0, NWD, NXC]CONT
+    /// [MAT, 1, 451/ HL]TEXT                          <- reader position
+    /// ...                                            (NWD records)
+    /// [MAT, 1, 451/ blank, blank, MF, MT, NC, MOD]   (NXC records)
+    /// [MAT, 1, 0/ 0.0, 0.0, 0, 0, 0, 0]SEND
+    /// ```
+    ///
+    /// Exactly `nwd` **TEXT** records are read, `HL` fields are joined with
+    /// newlines (trailing blanks of each line removed). The `EndfReader` is
+    /// then positioned on the first directory record (see
+    /// [`EndfReader::read_dir`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::{Cont, EndfReader};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut reader = EndfReader::new(BufReader::new(File::open("file.endf")?));
+    /// reader.read_head()?;
+    /// reader.read_cont()?;
+    /// reader.read_cont()?;
+    /// let Cont(_, _, _, _, nwd, _) = reader.read_cont()?;
+    /// println!("{}", reader.read_mt451_description(nwd as usize)?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - non ASCII text ([`EndfError::Encoding`])
+    /// - line shorter than 66 columns
+    /// - section ends before `nwd` records ([`EndfError::Data`])
+    /// - end of file is reached before `nwd` records
+    pub fn read_mt451_description(&mut self, nwd: usize) -> Result<String, EndfError> {
+        self.located(|reader| {
+            let mut buf = Vec::with_capacity(ENDF_MAX_LINE_LENGTH);
+            let mut lines = Vec::with_capacity(nwd);
+            for _ in 0..nwd {
+                reader.read_record(&mut buf)?;
+                if let Some((_, _, 0, _)) = parse_control_numbers(&buf)? {
+                    return Err(EndfError::Data);
+                }
+                let Text(hl) = parse_text(&buf)?;
+                lines.push(hl.trim_end().to_owned());
+            }
+            Ok(lines.join("\n"))
+        })
+    }
+
+    // Reads a record line into `buf`, without line ending.
+    fn read_record(&mut self, buf: &mut Vec<u8>) -> Result<(), EndfError> {
+        buf.clear();
+        match self.buf.read_until(b'\n', buf) {
             Ok(0) => Err(EndfError::EndOfFile),
             Err(error) => Err(error.into()),
             Ok(_) => {
-                let hl = match String::from_utf8(buf[..66].to_vec()) {
-                    Ok(string) => string,
-                    Err(_) => return Err(EndfError::Data),
-                };
-                Ok(Text(hl))
+                self.line += 1;
+                let len = trim_line_ending(buf).len();
+                buf.truncate(len);
+                self.validate(buf)
             }
         }
     }
+
+    // Checks sequence number of `record` against previous record (if validation is enabled).
+    fn validate(&mut self, record: &[u8]) -> Result<(), EndfError> {
+        if !self.validation {
+            return Ok(());
+        }
+        let Some((mat, mf, mt, ns)) = parse_control_numbers(record)? else {
+            return Ok(());
+        };
+        let Some(ns) = ns else {
+            return Ok(());
+        };
+        if mt == 0 {
+            self.previous = None;
+            return Ok(());
+        }
+        let expected = match self.previous {
+            Some((pmat, pmf, pmt, pns)) if (pmat, pmf, pmt) == (mat, mf, mt) => pns + 1,
+            _ => 1,
+        };
+        if ns != expected {
+            return Err(EndfError::Data);
+        }
+        self.previous = Some((mat, mf, mt, ns));
+        Ok(())
+    }
+
+    // Attaches current line number (unless already attached) to errors (except end of file) of `read`.
+    fn located<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T, EndfError>,
+    ) -> Result<T, EndfError> {
+        read(self).map_err(|error| match error {
+            EndfError::EndOfFile | EndfError::At { .. } => error,
+            error => EndfError::At {
+                line: self.line,
+                source: Box::new(error),
+            },
+        })
+    }
+}
+
+#[cfg(feature = "flate2")]
+impl<R: Read> EndfReader<BufReader<GzDecoder<R>>> {
+    /// Creates an `EndfReader` from gzip-compressed source.
+    ///
+    /// Available with the `flate2` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use nkl::data::endf::EndfReader;
+    ///
+    /// let path = "path/to/file.endf.gz";
+    /// let file = File::open(path).expect("could not open endf file");
+    /// let endf_reader = EndfReader::from_gzip(file);
+    /// ```
+    pub fn from_gzip(reader: R) -> Self {
+        Self::new(BufReader::new(GzDecoder::new(reader)))
+    }
+}
+
+// Remove trailing `\n` or `\r\n` line ending.
+pub(super) fn trim_line_ending(buf: &[u8]) -> &[u8] {
+    let buf = buf.strip_suffix(b"\n").unwrap_or(buf);
+    buf.strip_suffix(b"\r").unwrap_or(buf)
 }
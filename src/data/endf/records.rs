@@ -1,12 +1,98 @@
 /// ENDF **CONT** record.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nkl::data::endf::EndfReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let endf = " 1.00000000 2.00000000          1          2          3          4123412123    1\n";
+/// let cont = EndfReader::new(Cursor::new(endf)).read_cont()?;
+/// assert_eq!((cont.c1(), cont.c2()), (1., 2.));
+/// assert_eq!((cont.l1(), cont.l2(), cont.n1(), cont.n2()), (1, 2, 3, 4));
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct Cont(pub f64, pub f64, pub i64, pub i64, pub i64, pub i64);
 
+/// ENDF **MF=1/MT=451** directory entry.
+///
+/// Describes a section of the material, as read by
+/// [`EndfReader::read_dir`](crate::data::endf::EndfReader::read_dir).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DirEntry {
+    /// File number `MF` of the section.
+    pub mf: u32,
+    /// Section number `MT`.
+    pub mt: u32,
+    /// Number of lines `NC` of the section.
+    pub lines: u32,
+    /// Modification number `MOD` of the section.
+    pub modification: u32,
+}
+
+/// ENDF **HEAD** record.
+///
+/// A **HEAD** record is a **CONT** record whose `C1` and `C2` fields carry
+/// the `ZA` identifier and the atomic weight ratio `AWR`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nkl::data::endf::EndfReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let endf = " 1.00000000 2.00000000          1          2          3          4123412123    1\n";
+/// let head = EndfReader::new(Cursor::new(endf)).read_head()?;
+/// assert_eq!((head.za(), head.awr()), (1., 2.));
+/// assert_eq!((head.l1(), head.l2(), head.n1(), head.n2()), (1, 2, 3, 4));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Head(pub f64, pub f64, pub i64, pub i64, pub i64, pub i64);
+
 /// ENDF **INTG** record.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nkl::data::endf::EndfReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let endf = "1234512345 1234123412341234123412341234123412341234123412341234   12341212312345\n";
+/// let intg = EndfReader::new(Cursor::new(endf)).read_intg(3)?;
+/// assert_eq!((intg.ii(), intg.jj()), (12345, 12345));
+/// assert_eq!(intg.kij(), [1234; 13]);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Intg(pub i64, pub i64, pub Vec<i64>);
 
 /// ENDF **LIST** record.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nkl::data::endf::EndfReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let endf = concat!(
+///     " 1.00000000 2.00000000          1          2          3          4123412123    1\n",
+///     " 1.00000000 2.00000000 3.00000000 0.00000000 0.00000000 0.00000000123412123    2\n",
+/// );
+/// let list = EndfReader::new(Cursor::new(endf)).read_list()?;
+/// assert_eq!(list.npl(), 3);
+/// assert_eq!(list.values(), [1., 2., 3.]);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct List(
     pub f64,
@@ -19,6 +105,26 @@ pub struct List(
 );
 
 /// ENDF **TAB1** record.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nkl::data::endf::EndfReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let endf = concat!(
+///     " 1.00000000 2.00000000          1          2          1          2123412123    1\n",
+///     "          2          2          0          0          0          0123412123    2\n",
+///     " 1.00000000 2.00000000 3.00000000 4.00000000 0.00000000 0.00000000123412123    3\n",
+/// );
+/// let tab1 = EndfReader::new(Cursor::new(endf)).read_tab1()?;
+/// assert_eq!((tab1.nr(), tab1.np()), (1, 2));
+/// assert_eq!(tab1.regions(), [(2, 2)]);
+/// assert_eq!(tab1.points(), [(1., 2.), (3., 4.)]);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct Tab1(
     pub f64,
@@ -32,6 +138,24 @@ pub struct Tab1(
 );
 
 /// ENDF **TAB2** record.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nkl::data::endf::EndfReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let endf = concat!(
+///     " 1.00000000 2.00000000          1          2          3          4123412123    1\n",
+///     "          1          2          3          4          5          6123412123    2\n",
+/// );
+/// let tab2 = EndfReader::new(Cursor::new(endf)).read_tab2()?;
+/// assert_eq!((tab2.nr(), tab2.nz()), (3, 4));
+/// assert_eq!(tab2.regions(), [(1, 2), (3, 4), (5, 6)]);
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, PartialEq)]
 pub struct Tab2(
     pub f64,
@@ -44,5 +168,448 @@ pub struct Tab2(
 );
 
 /// ENDF **TEXT** record.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use nkl::data::endf::EndfReader;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let endf = "ABCDEFGHIJKLMNOPQRSTUVWXYZ abcdefghijklmnopqrstuvwxyz 0123456789  123412123    1\n";
+/// let text = EndfReader::new(Cursor::new(endf)).read_text()?;
+/// assert!(text.hl().starts_with("ABCDEFGHIJKLMNOPQRSTUVWXYZ"));
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Text(pub String);
+
+/// ENDF record kind, identified from control numbers with
+/// [`record_kind`](super::record_kind).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RecordKind {
+    /// Section end record (**SEND**).
+    Send,
+    /// File end record (**FEND**).
+    Fend,
+    /// Material end record (**MEND**).
+    Mend,
+    /// Tape end record (**TEND**).
+    Tend,
+    /// Data record (any record within a section).
+    Data,
+}
+
+impl Cont {
+    /// Returns `C1` field.
+    pub fn c1(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns `C2` field.
+    pub fn c2(&self) -> f64 {
+        self.1
+    }
+
+    /// Returns `L1` field.
+    pub fn l1(&self) -> i64 {
+        self.2
+    }
+
+    /// Returns `L2` field.
+    pub fn l2(&self) -> i64 {
+        self.3
+    }
+
+    /// Returns `N1` field.
+    pub fn n1(&self) -> i64 {
+        self.4
+    }
+
+    /// Returns `N2` field.
+    pub fn n2(&self) -> i64 {
+        self.5
+    }
+}
+
+impl Head {
+    /// Returns `ZA` identifier.
+    pub fn za(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns atomic weight ratio `AWR`.
+    pub fn awr(&self) -> f64 {
+        self.1
+    }
+
+    /// Returns `L1` field.
+    pub fn l1(&self) -> i64 {
+        self.2
+    }
+
+    /// Returns `L2` field.
+    pub fn l2(&self) -> i64 {
+        self.3
+    }
+
+    /// Returns `N1` field.
+    pub fn n1(&self) -> i64 {
+        self.4
+    }
+
+    /// Returns `N2` field.
+    pub fn n2(&self) -> i64 {
+        self.5
+    }
+}
+
+impl Intg {
+    /// Returns row index `II`.
+    pub fn ii(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns column index `JJ`.
+    pub fn jj(&self) -> i64 {
+        self.1
+    }
+
+    /// Returns `KIJ` values.
+    pub fn kij(&self) -> &[i64] {
+        &self.2
+    }
+
+    /// Returns row index `II` of the first value (alias of [`Intg::ii`]).
+    pub fn row(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns column index `JJ` of the first value (alias of [`Intg::jj`]).
+    pub fn col(&self) -> i64 {
+        self.1
+    }
+
+    /// Returns packed values `KIJ` (alias of [`Intg::kij`]).
+    pub fn values(&self) -> &[i64] {
+        &self.2
+    }
+
+    /// Returns an iterator over `(i, j, value)` matrix entries of the record.
+    ///
+    /// # Format
+    ///
+    /// An **INTG** record packs consecutive values of a single matrix row:
+    /// `KIJ(k)` is the value at row `II` and column `JJ + k` (`k` being the
+    /// 0-based index in `KIJ`). Indices are 1-based as in the ENDF-6 format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::endf::Intg;
+    ///
+    /// let intg = Intg(3, 2, vec![10, -5, 0]);
+    /// let entries: Vec<_> = intg.entries().collect();
+    /// assert_eq!(entries, [(3, 2, 10), (3, 3, -5), (3, 4, 0)]);
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// No entries are yielded if `II` or `JJ` is negative (invalid record).
+    pub fn entries(&self) -> impl Iterator<Item = (usize, usize, i64)> + '_ {
+        let origin = usize::try_from(self.0)
+            .ok()
+            .zip(usize::try_from(self.1).ok());
+        origin.into_iter().flat_map(move |(i, j)| {
+            self.2
+                .iter()
+                .enumerate()
+                .map(move |(k, &value)| (i, j + k, value))
+        })
+    }
+}
+
+impl List {
+    /// Returns `C1` field.
+    pub fn c1(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns `C2` field.
+    pub fn c2(&self) -> f64 {
+        self.1
+    }
+
+    /// Returns `L1` field.
+    pub fn l1(&self) -> i64 {
+        self.2
+    }
+
+    /// Returns `L2` field.
+    pub fn l2(&self) -> i64 {
+        self.3
+    }
+
+    /// Returns number of values `NPL`.
+    pub fn npl(&self) -> usize {
+        self.4
+    }
+
+    /// Returns `N2` field.
+    pub fn n2(&self) -> i64 {
+        self.5
+    }
+
+    /// Returns values `B(n)`.
+    pub fn values(&self) -> &[f64] {
+        &self.6
+    }
+
+    /// Returns an iterator over values `B(n)` grouped by `width`.
+    ///
+    /// The record does not carry the layout of its values: the caller must know
+    /// the intended width (e.g. 6 for resonance parameters). If values count
+    /// is not a multiple of `width`, the last chunk is shorter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::endf::List;
+    ///
+    /// let list = List(0., 0., 0, 0, 5, 0, vec![1., 2., 3., 4., 5.]);
+    /// let chunks: Vec<&[f64]> = list.chunks(3).collect();
+    /// assert_eq!(chunks, [&[1., 2., 3.][..], &[4., 5.][..]]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is 0.
+    pub fn chunks(&self, width: usize) -> impl Iterator<Item = &[f64]> {
+        self.6.chunks(width)
+    }
+
+    /// Returns an iterator over values `B(n)` grouped by pairs.
+    ///
+    /// The record does not carry the layout of its values: the caller must know
+    /// values are pairs. If values count is odd, the last value is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::endf::List;
+    ///
+    /// let list = List(0., 0., 0, 0, 4, 0, vec![1., 2., 3., 4.]);
+    /// let pairs: Vec<(f64, f64)> = list.pairs().collect();
+    /// assert_eq!(pairs, [(1., 2.), (3., 4.)]);
+    /// ```
+    pub fn pairs(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.6.chunks_exact(2).map(|pair| (pair[0], pair[1]))
+    }
+}
+
+impl Tab1 {
+    /// Returns `C1` field.
+    pub fn c1(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns `C2` field.
+    pub fn c2(&self) -> f64 {
+        self.1
+    }
+
+    /// Returns `L1` field.
+    pub fn l1(&self) -> i64 {
+        self.2
+    }
+
+    /// Returns `L2` field.
+    pub fn l2(&self) -> i64 {
+        self.3
+    }
+
+    /// Returns number of interpolation regions `NR`.
+    pub fn nr(&self) -> usize {
+        self.4
+    }
+
+    /// Returns number of points `NP`.
+    pub fn np(&self) -> usize {
+        self.5
+    }
+
+    /// Returns interpolation regions `(NBT, INT)`.
+    pub fn regions(&self) -> &[(u32, usize)] {
+        &self.6
+    }
+
+    /// Returns points `(X, Y)`.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.7
+    }
+
+    /// Evaluates the tabulated function at `x`.
+    ///
+    /// Supported ENDF interpolation laws are:
+    /// - `1`: histogram (y is constant and equal to the left value)
+    /// - `2`: linear-linear
+    /// - `3`: linear-log (y is linear in ln(x))
+    /// - `4`: log-linear (ln(y) is linear in x)
+    /// - `5`: log-log (ln(y) is linear in ln(x))
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::endf::Tab1;
+    ///
+    /// let tab1 = Tab1(0., 0., 0, 0, 1, 2, vec![(2, 2)], vec![(1., 1.), (3., 5.)]);
+    /// assert_eq!(tab1.interpolate(2.), Some(3.));
+    /// assert_eq!(tab1.interpolate(4.), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if:
+    /// - `x` is outside the tabulated range
+    /// - no interpolation region contains `x`
+    /// - interpolation law is not supported
+    pub fn interpolate(&self, x: f64) -> Option<f64> {
+        let points = &self.7;
+        let (first, last) = (points.first()?, points.last()?);
+        if !(first.0..=last.0).contains(&x) {
+            return None;
+        }
+        let upper = points.partition_point(|&(xi, _)| xi <= x);
+        if upper == points.len() {
+            return Some(last.1);
+        }
+        let (x1, y1) = points[upper - 1];
+        let (x2, y2) = points[upper];
+        // Interpolation region boundaries are 1-based point indices.
+        let &(_, law) = self.6.iter().find(|&&(nbt, _)| nbt as usize > upper)?;
+        match law {
+            1 => Some(y1),
+            2 => Some(y1 + (y2 - y1) * (x - x1) / (x2 - x1)),
+            3 => Some(y1 + (y2 - y1) * (x / x1).ln() / (x2 / x1).ln()),
+            4 => Some(y1 * ((x - x1) / (x2 - x1) * (y2 / y1).ln()).exp()),
+            5 => Some(y1 * ((x / x1).ln() / (x2 / x1).ln() * (y2 / y1).ln()).exp()),
+            _ => None,
+        }
+    }
+}
+
+impl Tab2 {
+    /// Returns `C1` field.
+    pub fn c1(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns `C2` field.
+    pub fn c2(&self) -> f64 {
+        self.1
+    }
+
+    /// Returns `L1` field.
+    pub fn l1(&self) -> i64 {
+        self.2
+    }
+
+    /// Returns `L2` field.
+    pub fn l2(&self) -> i64 {
+        self.3
+    }
+
+    /// Returns number of interpolation regions `NR`.
+    pub fn nr(&self) -> usize {
+        self.4
+    }
+
+    /// Returns number of subsequent records `NZ`.
+    pub fn nz(&self) -> usize {
+        self.5
+    }
+
+    /// Returns interpolation regions `(NBT, INT)`.
+    pub fn regions(&self) -> &[(u32, usize)] {
+        &self.6
+    }
+}
+
+impl Text {
+    /// Returns `HL` text.
+    pub fn hl(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(law: usize) -> Tab1 {
+        let points = vec![(1., 2.), (2., 4.), (4., 8.)];
+        Tab1(0., 0., 0, 0, 1, 3, vec![(3, law)], points)
+    }
+
+    #[test]
+    fn interpolate_lin_lin() {
+        let tab1 = sample(2);
+        assert_eq!(tab1.interpolate(1.), Some(2.));
+        assert_eq!(tab1.interpolate(1.5), Some(3.));
+        assert_eq!(tab1.interpolate(3.), Some(6.));
+        assert_eq!(tab1.interpolate(4.), Some(8.));
+    }
+
+    #[test]
+    fn interpolate_histogram() {
+        let tab1 = sample(1);
+        assert_eq!(tab1.interpolate(1.5), Some(2.));
+        assert_eq!(tab1.interpolate(2.), Some(4.));
+        assert_eq!(tab1.interpolate(3.9), Some(4.));
+        assert_eq!(tab1.interpolate(4.), Some(8.));
+    }
+
+    #[test]
+    fn interpolate_log() {
+        let (x, y) = (3f64, 6f64);
+        let lin_log = 4. + 4. * (x / 2.).ln() / 2f64.ln();
+        let log_lin = 4. * ((x - 2.) / 2. * 2f64.ln()).exp();
+        assert!((sample(3).interpolate(x).unwrap() - lin_log).abs() < 1e-12);
+        assert!((sample(4).interpolate(x).unwrap() - log_lin).abs() < 1e-12);
+        assert!((sample(5).interpolate(x).unwrap() - y).abs() < 1e-12);
+    }
+
+    #[test]
+    fn interpolate_regions() {
+        let points = vec![(1., 1.), (2., 2.), (3., 4.)];
+        let tab1 = Tab1(0., 0., 0, 0, 2, 3, vec![(2, 1), (3, 2)], points);
+        assert_eq!(tab1.interpolate(1.5), Some(1.));
+        assert_eq!(tab1.interpolate(2.5), Some(3.));
+    }
+
+    #[test]
+    fn interpolate_out_of_range() {
+        let tab1 = sample(2);
+        assert_eq!(tab1.interpolate(0.5), None);
+        assert_eq!(tab1.interpolate(4.5), None);
+        assert_eq!(tab1.interpolate(f64::NAN), None);
+        assert_eq!(sample(6).interpolate(1.5), None);
+    }
+
+    #[test]
+    fn list_reshape() {
+        let values: Vec<f64> = (1..=12).map(f64::from).collect();
+        let list = List(0., 0., 0, 0, 12, 2, values);
+        let pairs: Vec<(f64, f64)> = list.pairs().collect();
+        assert_eq!(pairs.len(), 6);
+        assert_eq!(pairs[0], (1., 2.));
+        assert_eq!(pairs[5], (11., 12.));
+        let rows: Vec<&[f64]> = list.chunks(6).collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], [1., 2., 3., 4., 5., 6.]);
+        assert_eq!(rows[1], [7., 8., 9., 10., 11., 12.]);
+        let odd = List(0., 0., 0, 0, 3, 0, vec![1., 2., 3.]);
+        assert_eq!(odd.pairs().collect::<Vec<_>>(), [(1., 2.)]);
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::ops::Range;
+
+use super::read::trim_line_ending;
+use super::{parse_control_numbers, EndfError, EndfReader};
+
+/// ENDF tape indexed by material/file/section control numbers.
+///
+/// A `Tape` holds all lines (without line ending) of an ENDF tape and the
+/// line ranges of each `(MAT, MF, MT)` section.
+///
+/// The same `(MAT, MF, MT)` section may occur several times in a tape (e.g.
+/// NJOY PENDF/BROADR output repeats each material for every temperature),
+/// occurrences are kept in tape order.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use nkl::data::endf::Tape;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let file = File::open("path/to/file.endf")?;
+/// let tape = Tape::from_reader(BufReader::new(file))?;
+/// // MAT=125 (H1), MF=3 (cross sections), MT=102 (radiative capture)
+/// if let Some(lines) = tape.section(125, 3, 102) {
+///     println!("{} lines", lines.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tape {
+    lines: Vec<Vec<u8>>,
+    sections: Vec<((i32, u32, u32), Range<usize>)>,
+    // Indices into `sections` of each `(MAT, MF, MT)` occurrence.
+    index: HashMap<(i32, u32, u32), Vec<usize>>,
+}
+
+impl Tape {
+    /// Reads and indexes an ENDF tape from `reader`.
+    ///
+    /// # Format
+    ///
+    /// Section's lines are the consecutive lines sharing the same non-zero
+    /// `MT` control number. Tape identification and **SEND**, **FEND**,
+    /// **MEND** and **TEND** records are not part of any section. Lines of
+    /// an already indexed `(MAT, MF, MT)` section following other lines
+    /// start a new occurrence of the section.
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - malformed/invalid control numbers
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, EndfError> {
+        let mut lines = Vec::new();
+        let mut sections: Vec<((i32, u32, u32), Range<usize>)> = Vec::new();
+        let mut index_map: HashMap<(i32, u32, u32), Vec<usize>> = HashMap::new();
+        for (index, line) in EndfReader::new(reader).lines_iter().enumerate() {
+            let mut line = line?;
+            line.truncate(trim_line_ending(&line).len());
+            let at = |error| EndfError::At {
+                line: index + 1,
+                source: Box::new(error),
+            };
+            // skip tape identification
+            if index == 0 {
+                lines.push(line);
+                continue;
+            }
+            let (mat, mf, mt, _) = parse_control_numbers(&line)
+                .and_then(|controls| controls.ok_or(EndfError::Format))
+                .map_err(at)?;
+            lines.push(line);
+            if mt == 0 {
+                continue;
+            }
+            let key = (mat, mf, mt);
+            if let Some((last, range)) = sections.last_mut() {
+                if *last == key && range.end == index {
+                    range.end += 1;
+                    continue;
+                }
+            }
+            index_map.entry(key).or_default().push(sections.len());
+            sections.push((key, index..index + 1));
+        }
+        Ok(Self {
+            lines,
+            sections,
+            index: index_map,
+        })
+    }
+
+    /// Returns lines (without line ending) of the first occurrence of the
+    /// `(mat, mf, mt)` section.
+    ///
+    /// Refer to [`Tape::section_occurrences`] for all occurrences.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(lines)` if section exists
+    /// - `None` otherwise
+    pub fn section(&self, mat: i32, mf: u32, mt: u32) -> Option<&[Vec<u8>]> {
+        self.section_occurrences(mat, mf, mt).next()
+    }
+
+    /// Returns an iterator over lines (without line ending) of all occurrences
+    /// of the `(mat, mf, mt)` section, in tape order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use nkl::data::endf::Tape;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let file = File::open("path/to/file.pendf")?;
+    /// let tape = Tape::from_reader(BufReader::new(file))?;
+    /// // one MF=3/MT=1 section per temperature
+    /// for lines in tape.section_occurrences(125, 3, 1) {
+    ///     println!("{} lines", lines.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn section_occurrences(
+        &self,
+        mat: i32,
+        mf: u32,
+        mt: u32,
+    ) -> impl Iterator<Item = &[Vec<u8>]> + '_ {
+        self.index
+            .get(&(mat, mf, mt))
+            .into_iter()
+            .flatten()
+            .map(|&section| &self.lines[self.sections[section].1.clone()])
+    }
+
+    /// Returns an iterator over `(MAT, MF, MT)` control numbers of the tape's
+    /// sections, in tape order (repeated sections are yielded once per
+    /// occurrence).
+    pub fn sections(&self) -> impl Iterator<Item = (i32, u32, u32)> + '_ {
+        self.sections.iter().map(|(key, _)| *key)
+    }
+
+    /// Returns all lines (without line ending) of the tape.
+    pub fn lines(&self) -> &[Vec<u8>] {
+        &self.lines
+    }
+}
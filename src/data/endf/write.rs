@@ -0,0 +1,259 @@
+use std::io::Write;
+
+use super::{format_endf_float, Cont, EndfError, Head, List, Tab1, Tab2, Text};
+
+// Number of data columns (6 fields of 11 columns).
+const ENDF_DATA_LENGTH: usize = 66;
+
+// Largest magnitude of a 10 digits integer field.
+const ENDF_MAX_INTEGER: u64 = 9_999_999_999;
+
+/// Writer specialized for ENDF format files.
+///
+/// Records are written as 80 columns lines: 66 data columns (floats are
+/// formatted with [`format_endf_float`]) followed by the
+/// `MAT`/`MF`/`MT` control numbers of the current section (see
+/// [`EndfWriter::set_section`]) and the `NS` sequence number, incremented for
+/// each written line.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::data::endf::{Cont, EndfWriter};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut writer = EndfWriter::new(Vec::new());
+/// writer.set_section(125, 3, 1)?;
+/// writer.write_cont(&Cont(1001.0, 0.9991673, 0, 0, 0, 0))?;
+/// assert_eq!(
+///     writer.into_inner(),
+///     b" 1001.00000 0.99916730          0          0          0          0 125 3  1    1\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EndfWriter<W: Write> {
+    buf: W,
+    mat: i32,
+    mf: u32,
+    mt: u32,
+    ns: u32,
+}
+
+impl<W: Write> EndfWriter<W> {
+    /// Creates an `EndfWriter` to specified destination.
+    ///
+    /// Control numbers are initialized to `MAT=0`, `MF=0` and `MT=0`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use std::io::BufWriter;
+    /// use nkl::data::endf::EndfWriter;
+    ///
+    /// let path = "path/to/file.endf";
+    /// let file = File::create(path).expect("could not create endf file");
+    /// let endf_writer = EndfWriter::new(BufWriter::new(file));
+    /// ```
+    pub fn new(buf: W) -> Self {
+        Self {
+            buf,
+            mat: 0,
+            mf: 0,
+            mt: 0,
+            ns: 0,
+        }
+    }
+
+    /// Sets control numbers of next written lines and resets sequence number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::endf::EndfWriter;
+    ///
+    /// let mut writer = EndfWriter::new(Vec::new());
+    /// writer.set_section(9228, 3, 18).unwrap();
+    /// assert!(writer.set_section(12345, 3, 18).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Errors if control numbers do not fit their columns
+    /// ([`EndfError::Data`]):
+    /// - `mat` ∉ `[-1, 9999]`
+    /// - `mf > 99`
+    /// - `mt > 999`
+    ///
+    /// Control numbers are left unchanged on error.
+    pub fn set_section(&mut self, mat: i32, mf: u32, mt: u32) -> Result<(), EndfError> {
+        if !(-1..=9999).contains(&mat) || mf > 99 || mt > 999 {
+            return Err(EndfError::Data);
+        }
+        self.mat = mat;
+        self.mf = mf;
+        self.mt = mt;
+        self.ns = 0;
+        Ok(())
+    }
+
+    /// Unwraps the `EndfWriter`, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.buf
+    }
+
+    /// Writes a **CONT** record to the `EndfWriter`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - integer has more than 10 digits
+    /// - float is not finite
+    pub fn write_cont(&mut self, cont: &Cont) -> Result<(), EndfError> {
+        let Cont(c1, c2, l1, l2, n1, n2) = *cont;
+        let mut line = String::with_capacity(ENDF_DATA_LENGTH);
+        push_float(&mut line, c1)?;
+        push_float(&mut line, c2)?;
+        for integer in [l1, l2, n1, n2] {
+            push_integer(&mut line, integer)?;
+        }
+        self.write_record(&line)
+    }
+
+    /// Writes a **HEAD** record to the `EndfWriter`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - integer has more than 10 digits
+    /// - float is not finite
+    pub fn write_head(&mut self, head: &Head) -> Result<(), EndfError> {
+        let Head(za, awr, l1, l2, n1, n2) = *head;
+        self.write_cont(&Cont(za, awr, l1, l2, n1, n2))
+    }
+
+    /// Writes a **LIST** record to the `EndfWriter`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - integer has more than 10 digits
+    /// - float is not finite
+    pub fn write_list(&mut self, list: &List) -> Result<(), EndfError> {
+        let List(c1, c2, l1, l2, _, n2, b) = list;
+        self.write_cont(&Cont(*c1, *c2, *l1, *l2, b.len() as i64, *n2))?;
+        for chunk in b.chunks(6) {
+            let mut line = String::with_capacity(ENDF_DATA_LENGTH);
+            for &float in chunk {
+                push_float(&mut line, float)?;
+            }
+            self.write_record(&line)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a **TAB1** record to the `EndfWriter`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - integer has more than 10 digits
+    /// - float is not finite
+    pub fn write_tab1(&mut self, tab1: &Tab1) -> Result<(), EndfError> {
+        let Tab1(c1, c2, l1, l2, _, _, int, tab) = tab1;
+        let cont = Cont(*c1, *c2, *l1, *l2, int.len() as i64, tab.len() as i64);
+        self.write_cont(&cont)?;
+        self.write_interpolation(int)?;
+        for chunk in tab.chunks(3) {
+            let mut line = String::with_capacity(ENDF_DATA_LENGTH);
+            for &(x, y) in chunk {
+                push_float(&mut line, x)?;
+                push_float(&mut line, y)?;
+            }
+            self.write_record(&line)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a **TAB2** record to the `EndfWriter`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - integer has more than 10 digits
+    /// - float is not finite
+    pub fn write_tab2(&mut self, tab2: &Tab2) -> Result<(), EndfError> {
+        let Tab2(c1, c2, l1, l2, _, nz, int) = tab2;
+        let cont = Cont(*c1, *c2, *l1, *l2, int.len() as i64, *nz as i64);
+        self.write_cont(&cont)?;
+        self.write_interpolation(int)
+    }
+
+    /// Writes a **TEXT** record to the `EndfWriter`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - I/O error occurs
+    /// - text is longer than 66 bytes or spans multiple lines
+    /// - non ASCII text ([`EndfError::Encoding`])
+    pub fn write_text(&mut self, text: &Text) -> Result<(), EndfError> {
+        if !text.0.is_ascii() {
+            return Err(EndfError::Encoding);
+        }
+        if text.0.len() > ENDF_DATA_LENGTH || text.0.contains(['\n', '\r']) {
+            return Err(EndfError::Data);
+        }
+        self.write_record(&text.0)
+    }
+
+    // Writes `(NBT, INT)` interpolation regions of a TAB1/TAB2 record.
+    fn write_interpolation(&mut self, int: &[(u32, usize)]) -> Result<(), EndfError> {
+        for chunk in int.chunks(3) {
+            let mut line = String::with_capacity(ENDF_DATA_LENGTH);
+            for &(nbt, scheme) in chunk {
+                push_integer(&mut line, nbt.into())?;
+                let scheme = i64::try_from(scheme).map_err(|_| EndfError::Data)?;
+                push_integer(&mut line, scheme)?;
+            }
+            self.write_record(&line)?;
+        }
+        Ok(())
+    }
+
+    // Writes data columns (blank padded to 66 columns) followed by control numbers.
+    fn write_record(&mut self, data: &str) -> Result<(), EndfError> {
+        self.ns = if self.ns == 99999 { 1 } else { self.ns + 1 };
+        writeln!(
+            self.buf,
+            "{:<66}{:>4}{:>2}{:>3}{:>5}",
+            data, self.mat, self.mf, self.mt, self.ns
+        )?;
+        Ok(())
+    }
+}
+
+// Appends an 11 columns integer field (at most 10 digits).
+fn push_integer(line: &mut String, integer: i64) -> Result<(), EndfError> {
+    if integer.unsigned_abs() > ENDF_MAX_INTEGER {
+        return Err(EndfError::Data);
+    }
+    line.push_str(&format!("{integer:>11}"));
+    Ok(())
+}
+
+// Appends an 11 columns float field.
+fn push_float(line: &mut String, float: f64) -> Result<(), EndfError> {
+    if !float.is_finite() {
+        return Err(EndfError::Data);
+    }
+    line.push_str(&format_endf_float(float));
+    Ok(())
+}
@@ -2,72 +2,135 @@
 //!
 //! The [`mass`](`crate::data::mass`) module API consist of a single trait:
 //! [`AtomicMassLibrary`] which provides access to atomic mass data through the
-//! [`AtomicMassLibrary::get`] method.
+//! [`AtomicMassLibrary::get`] method, and derived quantities such as
+//! [`AtomicMassLibrary::mass_excess`].
 //!
 //! The module provides three main resources implementation for atomic mass data:
 //! - [ENDF/B VIII.0](`EndfbAtomicMassLibrary`)
 //! - [JEFF 3.3](`JeffAtomicMassLibrary`)
 //! - [JENDL 5](`JendlAtomicMassLibrary`)
+//!
+//! User provided atomic mass data can be loaded at runtime with
+//! [`CustomAtomicMassLibrary`].
+//!
+//! For one-off lookups, [`atomic_mass`] queries the default (ENDF/B) library
+//! and [`atomic_mass_from`] any embedded library selected with [`MassLibrary`].
+//!
+//! Derived nuclear quantities are available through free functions:
+//! - [`atomic_weight_ratio`]
+//! - [`binding_energy`]
+//! - [`reaction_q_value`]
 
 use std::{
-    cell::{Cell, UnsafeCell},
     collections::HashMap,
-    mem::MaybeUninit,
-    sync::Once,
+    error::Error,
+    fmt::Display,
+    io::{Error as IOError, Read},
 };
 
 use crate::core::Zai;
+use crate::lazy::Lazy;
 
-// Lazy initialization.
-// Replace with std implementation after stabilization.
-struct Lazy<T, F = fn() -> T> {
-    once: Once,
-    init: Cell<Option<F>>,
-    value: UnsafeCell<MaybeUninit<T>>,
-}
-
-impl<T, F: FnOnce() -> T> Lazy<T, F> {
-    const fn new(init: F) -> Self {
-        Lazy {
-            once: Once::new(),
-            init: Cell::new(Some(init)),
-            value: UnsafeCell::new(MaybeUninit::uninit()),
-        }
-    }
-
-    fn get(&self) -> &T {
-        self.once.call_once(|| {
-            let value = (self.init.take().unwrap())();
-            unsafe {
-                (*self.value.get()).write(value);
-            }
-        });
-        unsafe { (*self.value.get()).assume_init_ref() }
-    }
-}
+/// Neutron mass \[u\].
+pub const NEUTRON_MASS: f64 = 1.008_664_915_95;
 
-unsafe impl<T: Sync + Send, F: Sync + Send> Sync for Lazy<T, F> {}
-unsafe impl<T: Send, F: Send> Send for Lazy<T, F> {}
+/// Energy equivalent of the atomic mass unit \[MeV/u\].
+pub const ATOMIC_MASS_UNIT_ENERGY: f64 = 931.494_102_42;
 
 /// Atomic mass library trait.
 pub trait AtomicMassLibrary {
     /// Returns atomic mass of `zai`.
     fn get(&self, zai: Zai) -> Option<f64>;
+
+    /// Returns an iterator over all `(zai, atomic mass)` pairs of the library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::mass::{AtomicMassLibrary, EndfbAtomicMassLibrary};
+    ///
+    /// for (zai, mass) in EndfbAtomicMassLibrary.iter() {
+    ///     println!("{}: {}", zai.name(), mass);
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// Iteration order is unspecified.
+    fn iter(&self) -> Box<dyn Iterator<Item = (Zai, f64)> + '_>;
+
+    /// Returns number of nuclides of the library.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::data::mass::{AtomicMassLibrary, EndfbAtomicMassLibrary};
+    ///
+    /// assert!(EndfbAtomicMassLibrary.len() > 3000);
+    /// ```
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the library holds no nuclide.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the library holds atomic mass of `zai`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    /// use nkl::data::mass::{AtomicMassLibrary, EndfbAtomicMassLibrary};
+    ///
+    /// assert!(EndfbAtomicMassLibrary.contains(Zai::new(92, 235, 0)));
+    /// assert!(!EndfbAtomicMassLibrary.contains(Zai::new(118, 400, 0)));
+    /// ```
+    fn contains(&self, zai: Zai) -> bool {
+        self.get(zai).is_some()
+    }
+
+    /// Returns mass excess \[MeV\] of `zai`.
+    ///
+    /// # Format
+    ///
+    /// ```text
+    /// Δ = (m(ZAI) - A) × c²
+    /// ```
+    ///
+    /// with:
+    /// - `m(ZAI)`: atomic mass of `zai` \[u\]
+    /// - `A`: mass number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nkl::core::Zai;
+    /// use nkl::data::mass::{AtomicMassLibrary, EndfbAtomicMassLibrary};
+    ///
+    /// let c12 = Zai::new(6, 12, 0);
+    /// let excess = EndfbAtomicMassLibrary.mass_excess(c12).unwrap();
+    /// assert!(excess.abs() < 1e-6);
+    /// ```
+    fn mass_excess(&self, zai: Zai) -> Option<f64> {
+        let mass = self.get(zai)?;
+        Some((mass - zai.mass_number() as f64) * ATOMIC_MASS_UNIT_ENERGY)
+    }
 }
 
 static ENDFB_ATOMIC_MASSES: Lazy<HashMap<Zai, f64>> = Lazy::new(|| {
     let source = include_str!("../../data/atomic_masses/endfb");
-    init_atomic_masses(source)
+    init_atomic_masses(source).expect("invalid embedded ENDF/B atomic mass data")
 });
 
 static JEFF_ATOMIC_MASSES: Lazy<HashMap<Zai, f64>> = Lazy::new(|| {
     let source = include_str!("../../data/atomic_masses/jeff");
-    init_atomic_masses(source)
+    init_atomic_masses(source).expect("invalid embedded JEFF atomic mass data")
 });
 
 static JENDL_ATOMIC_MASSES: Lazy<HashMap<Zai, f64>> = Lazy::new(|| {
     let source = include_str!("../../data/atomic_masses/jendl");
-    init_atomic_masses(source)
+    init_atomic_masses(source).expect("invalid embedded JENDL atomic mass data")
 });
 
 /// ENDF/B atomic mass library.
@@ -87,6 +150,19 @@ impl AtomicMassLibrary for EndfbAtomicMassLibrary {
     fn get(&self, zai: Zai) -> Option<f64> {
         ENDFB_ATOMIC_MASSES.get().get(&zai).copied()
     }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Zai, f64)> + '_> {
+        Box::new(
+            ENDFB_ATOMIC_MASSES
+                .get()
+                .iter()
+                .map(|(&zai, &mass)| (zai, mass)),
+        )
+    }
+
+    fn len(&self) -> usize {
+        ENDFB_ATOMIC_MASSES.get().len()
+    }
 }
 
 /// JEFF atomic mass library.
@@ -107,6 +183,19 @@ impl AtomicMassLibrary for JeffAtomicMassLibrary {
     fn get(&self, zai: Zai) -> Option<f64> {
         JEFF_ATOMIC_MASSES.get().get(&zai).copied()
     }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Zai, f64)> + '_> {
+        Box::new(
+            JEFF_ATOMIC_MASSES
+                .get()
+                .iter()
+                .map(|(&zai, &mass)| (zai, mass)),
+        )
+    }
+
+    fn len(&self) -> usize {
+        JEFF_ATOMIC_MASSES.get().len()
+    }
 }
 
 /// JENDL atomic mass library.
@@ -127,17 +216,536 @@ impl AtomicMassLibrary for JendlAtomicMassLibrary {
     fn get(&self, zai: Zai) -> Option<f64> {
         JENDL_ATOMIC_MASSES.get().get(&zai).copied()
     }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Zai, f64)> + '_> {
+        Box::new(
+            JENDL_ATOMIC_MASSES
+                .get()
+                .iter()
+                .map(|(&zai, &mass)| (zai, mass)),
+        )
+    }
+
+    fn len(&self) -> usize {
+        JENDL_ATOMIC_MASSES.get().len()
+    }
+}
+
+/// Runtime loaded atomic mass library.
+///
+/// # Format
+///
+/// Atomic mass data must follow the fixed-column format of embedded libraries:
+///
+/// ```text
+/// ..3    : atomic number (Z)
+/// 4..7   : mass number (A)
+/// 8..9   : isomeric state number (I)
+/// 10..17 : nuclide's name (XxAAAmI)
+/// 18..31 : nuclide's element name
+/// 32..34 : nuclide's element symbol
+/// 35..   : nuclide's atomic mass [u]
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::Zai;
+/// use nkl::data::mass::{AtomicMassLibrary, CustomAtomicMassLibrary};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let source = "  1   1 0 H1      Hydrogen      H      1.007825";
+/// let library = CustomAtomicMassLibrary::from_reader(source.as_bytes())?;
+/// assert_eq!(library.get(Zai::new(1, 1, 0)), Some(1.007825));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CustomAtomicMassLibrary {
+    masses: HashMap<Zai, f64>,
+}
+
+impl CustomAtomicMassLibrary {
+    /// Creates a `CustomAtomicMassLibrary` from specified source.
+    ///
+    /// # Errors
+    ///
+    /// [`MassError`] is returned if:
+    /// - I/O error occurs
+    /// - malformed/invalid data
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, MassError> {
+        let mut source = String::new();
+        reader.read_to_string(&mut source)?;
+        let masses = init_atomic_masses(&source)?;
+        Ok(Self { masses })
+    }
+}
+
+impl AtomicMassLibrary for CustomAtomicMassLibrary {
+    fn get(&self, zai: Zai) -> Option<f64> {
+        self.masses.get(&zai).copied()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Zai, f64)> + '_> {
+        Box::new(self.masses.iter().map(|(&zai, &mass)| (zai, mass)))
+    }
+
+    fn len(&self) -> usize {
+        self.masses.len()
+    }
+}
+
+/// Embedded atomic mass libraries.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum MassLibrary {
+    /// ENDF/B library ([`EndfbAtomicMassLibrary`]).
+    #[default]
+    Endfb,
+    /// JEFF library ([`JeffAtomicMassLibrary`]).
+    Jeff,
+    /// JENDL library ([`JendlAtomicMassLibrary`]).
+    Jendl,
+}
+
+/// Returns atomic mass \[u\] of `zai` from the default (ENDF/B) library.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::Zai;
+/// use nkl::data::mass::atomic_mass;
+///
+/// let u235 = Zai::new(92, 235, 0);
+/// assert!((atomic_mass(u235).unwrap() - 235.0439).abs() < 1e-4);
+/// ```
+pub fn atomic_mass(zai: Zai) -> Option<f64> {
+    atomic_mass_from(MassLibrary::default(), zai)
+}
+
+/// Returns atomic mass \[u\] of `zai` from specified embedded `library`.
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::Zai;
+/// use nkl::data::mass::{atomic_mass_from, MassLibrary};
+///
+/// let h1 = Zai::new(1, 1, 0);
+/// assert!((atomic_mass_from(MassLibrary::Jeff, h1).unwrap() - 1.007825).abs() < 1e-6);
+/// ```
+pub fn atomic_mass_from(library: MassLibrary, zai: Zai) -> Option<f64> {
+    match library {
+        MassLibrary::Endfb => EndfbAtomicMassLibrary.get(zai),
+        MassLibrary::Jeff => JeffAtomicMassLibrary.get(zai),
+        MassLibrary::Jendl => JendlAtomicMassLibrary.get(zai),
+    }
+}
+
+/// Returns atomic weight ratio of `zai` computed from `library`.
+///
+/// # Format
+///
+/// ```text
+/// AWR = m(ZAI) / m(n)
+/// ```
+///
+/// with:
+/// - `m(ZAI)`: atomic mass of `zai` from `library`
+/// - `m(n)`: [`NEUTRON_MASS`] (CODATA 2018, 1.00866491595 u)
+///
+/// # Returns
+///
+/// - `Some(ratio)` if `zai` atomic mass is available in `library`
+/// - `None` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::Zai;
+/// use nkl::data::mass::{atomic_weight_ratio, EndfbAtomicMassLibrary};
+///
+/// let u235 = Zai::new(92, 235, 0);
+/// let awr = atomic_weight_ratio(u235, &EndfbAtomicMassLibrary).unwrap();
+/// assert!((awr - 233.0248).abs() < 1e-3);
+/// ```
+pub fn atomic_weight_ratio(zai: Zai, library: &dyn AtomicMassLibrary) -> Option<f64> {
+    Some(library.get(zai)? / NEUTRON_MASS)
+}
+
+/// Returns nuclear binding energy \[MeV\] of `zai` computed from `library`.
+///
+/// # Format
+///
+/// ```text
+/// B = (Z × m(H1) + N × m(n) - m(ZAI)) × c²
+/// ```
+///
+/// with:
+/// - `Z`: number of protons
+/// - `N`: number of neutrons
+/// - `m(H1)`: atomic mass of hydrogen-1 from `library`
+/// - `m(n)`: [`NEUTRON_MASS`]
+/// - `m(ZAI)`: atomic mass of `zai` from `library`
+///
+/// # Returns
+///
+/// - `Some(energy)` if hydrogen-1 and `zai` atomic masses are available in `library`
+/// - `None` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::Zai;
+/// use nkl::data::mass::{binding_energy, EndfbAtomicMassLibrary};
+///
+/// let fe56 = Zai::new(26, 56, 0);
+/// let energy = binding_energy(fe56, &EndfbAtomicMassLibrary).unwrap();
+/// assert!((energy / 56. - 8.79).abs() < 0.01);
+/// ```
+pub fn binding_energy(zai: Zai, library: &dyn AtomicMassLibrary) -> Option<f64> {
+    let hydrogen_mass = library.get(Zai::new(1, 1, 0))?;
+    let atomic_mass = library.get(zai)?;
+    let protons = zai.protons() as f64;
+    let neutrons = zai.neutrons() as f64;
+    let defect = protons * hydrogen_mass + neutrons * NEUTRON_MASS - atomic_mass;
+    Some(defect * ATOMIC_MASS_UNIT_ENERGY)
+}
+
+/// Returns Q-value \[MeV\] of a nuclear reaction computed from `library`.
+///
+/// # Format
+///
+/// ```text
+/// Q = (Σ m(reactants) - Σ m(products)) × c²
+/// ```
+///
+/// Neutrons are not nuclides: an incident neutron is given as a `None`
+/// projectile, and outgoing neutrons are implied by conservation of the
+/// number of nucleons (`products` only hold residual nuclides and light
+/// charged particles, photons being massless).
+///
+/// # Returns
+///
+/// - `Some(q)` if all atomic masses are available in `library`
+/// - `None` otherwise, or if the reaction does not conserve the number of
+///   protons (or has less nucleons before than after the reaction)
+///
+/// # Examples
+///
+/// ```
+/// use nkl::core::Zai;
+/// use nkl::data::mass::{reaction_q_value, EndfbAtomicMassLibrary};
+///
+/// // U235(n,γ)U236
+/// let u235 = Zai::new(92, 235, 0);
+/// let u236 = Zai::new(92, 236, 0);
+/// let q = reaction_q_value(u235, None, &[u236], &EndfbAtomicMassLibrary).unwrap();
+/// assert!((q - 6.545).abs() < 0.05);
+///
+/// // U238(n,2n)U237: two outgoing neutrons are implied
+/// let u238 = Zai::new(92, 238, 0);
+/// let u237 = Zai::new(92, 237, 0);
+/// let q = reaction_q_value(u238, None, &[u237], &EndfbAtomicMassLibrary).unwrap();
+/// assert!((q + 6.152).abs() < 0.1);
+/// ```
+///
+/// # Notes
+///
+/// - Atomic masses include electrons masses, which cancel out as the number
+///   of protons is conserved.
+/// - Embedded libraries atomic masses are given with 6 decimals (and may
+///   differ from AME evaluation by a few 1e-5 u): resulting Q-values are
+///   accurate to a few tens of keV.
+pub fn reaction_q_value(
+    target: Zai,
+    projectile: Option<Zai>,
+    products: &[Zai],
+    library: &dyn AtomicMassLibrary,
+) -> Option<f64> {
+    let reactants: Vec<Zai> = std::iter::once(target).chain(projectile).collect();
+    let protons = |nuclides: &[Zai]| nuclides.iter().map(Zai::protons).sum::<u32>();
+    let nucleons = |nuclides: &[Zai]| nuclides.iter().map(Zai::nucleons).sum::<u32>();
+    if protons(&reactants) != protons(products) {
+        return None;
+    }
+    // incident neutron (if any) and outgoing neutrons
+    let neutrons_in = u32::from(projectile.is_none());
+    let neutrons_out = (nucleons(&reactants) + neutrons_in).checked_sub(nucleons(products))?;
+    let mut defect = 0.;
+    for zai in &reactants {
+        defect += library.get(*zai)?;
+    }
+    for zai in products {
+        defect -= library.get(*zai)?;
+    }
+    defect += (neutrons_in as f64 - neutrons_out as f64) * NEUTRON_MASS;
+    Some(defect * ATOMIC_MASS_UNIT_ENERGY)
+}
+
+/// Error returned when parsing atomic mass data fails.
+#[derive(Debug, Eq, PartialEq)]
+pub struct MassParseError {
+    line: usize,
+}
+
+impl MassParseError {
+    /// Returns the line number (1-based) of the malformed record.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl Display for MassParseError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(fmt, "invalid atomic mass data at line {}", self.line)
+    }
+}
+
+impl Error for MassParseError {}
+
+/// The error type for [`mass`](crate::data::mass) module.
+#[derive(Debug)]
+pub enum MassError {
+    /// I/O error.
+    IO(IOError),
+    /// Invalid atomic mass data.
+    Parse(MassParseError),
+}
+
+impl Display for MassError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MassError::IO(_) => write!(fmt, "atomic mass I/O error"),
+            MassError::Parse(error) => write!(fmt, "{error}"),
+        }
+    }
+}
+
+impl Error for MassError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MassError::IO(error) => Some(error),
+            MassError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<IOError> for MassError {
+    fn from(error: IOError) -> Self {
+        MassError::IO(error)
+    }
+}
+
+impl From<MassParseError> for MassError {
+    fn from(error: MassParseError) -> Self {
+        MassError::Parse(error)
+    }
 }
 
-fn init_atomic_masses(source: &str) -> HashMap<Zai, f64> {
+fn init_atomic_masses(source: &str) -> Result<HashMap<Zai, f64>, MassParseError> {
     let mut table = HashMap::new();
-    for line in source.lines() {
-        let z: u32 = line[..3].trim().parse().unwrap();
-        let a: u32 = line[4..7].trim().parse().unwrap();
-        let i: u32 = line[8..9].trim().parse().unwrap();
-        let zai = Zai::new(z, a, i);
-        let mass: f64 = line[35..].trim().parse().unwrap();
+    for (index, line) in source.lines().enumerate() {
+        let error = MassParseError { line: index + 1 };
+        let (zai, mass) = parse_atomic_mass(line).ok_or(error)?;
         table.insert(zai, mass);
     }
-    table
+    Ok(table)
+}
+
+fn parse_atomic_mass(line: &str) -> Option<(Zai, f64)> {
+    let z: u32 = line.get(..3)?.trim().parse().ok()?;
+    let a: u32 = line.get(4..7)?.trim().parse().ok()?;
+    let i: u32 = line.get(8..9)?.trim().parse().ok()?;
+    if a >= 1000 || i >= 10 {
+        return None;
+    }
+    let zai = Zai::from_id(z * 10000 + a * 10 + i)?;
+    let mass: f64 = line.get(35..)?.trim().parse().ok()?;
+    Some((zai, mass))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_library() {
+        for (zai, mass) in EndfbAtomicMassLibrary.iter() {
+            assert_eq!(atomic_mass(zai), Some(mass));
+        }
+        assert_eq!(atomic_mass(Zai::new(118, 400, 0)), None);
+    }
+
+    #[test]
+    fn library_selection() {
+        let u238 = Zai::new(92, 238, 0);
+        assert_eq!(
+            atomic_mass_from(MassLibrary::Endfb, u238),
+            EndfbAtomicMassLibrary.get(u238)
+        );
+        assert_eq!(
+            atomic_mass_from(MassLibrary::Jeff, u238),
+            JeffAtomicMassLibrary.get(u238)
+        );
+        assert_eq!(
+            atomic_mass_from(MassLibrary::Jendl, u238),
+            JendlAtomicMassLibrary.get(u238)
+        );
+    }
+
+    #[test]
+    fn atomic_weight_ratio_ace() {
+        // ACE table 92235.80c header
+        let u235 = Zai::new(92, 235, 0);
+        for library in [
+            &EndfbAtomicMassLibrary as &dyn AtomicMassLibrary,
+            &JeffAtomicMassLibrary,
+            &JendlAtomicMassLibrary,
+        ] {
+            let awr = atomic_weight_ratio(u235, library).unwrap();
+            assert!((awr - 233.0248).abs() < 1e-3);
+        }
+        assert!(atomic_weight_ratio(Zai::new(118, 400, 0), &EndfbAtomicMassLibrary).is_none());
+    }
+
+    #[test]
+    fn binding_energy_per_nucleon() {
+        let fe56 = Zai::new(26, 56, 0);
+        for library in [
+            &EndfbAtomicMassLibrary as &dyn AtomicMassLibrary,
+            &JeffAtomicMassLibrary,
+            &JendlAtomicMassLibrary,
+        ] {
+            let energy = binding_energy(fe56, library).unwrap();
+            assert!((energy / 56. - 8.8).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn binding_energy_missing() {
+        let og400 = Zai::new(118, 400, 0);
+        assert!(binding_energy(og400, &EndfbAtomicMassLibrary).is_none());
+    }
+
+    #[test]
+    fn reaction_q_value_capture() {
+        // U235(n,γ)U236: neutron separation energy of U236 (6545.5 keV, AME2020)
+        let u235 = Zai::new(92, 235, 0);
+        let u236 = Zai::new(92, 236, 0);
+        for library in [
+            &EndfbAtomicMassLibrary as &dyn AtomicMassLibrary,
+            &JeffAtomicMassLibrary,
+            &JendlAtomicMassLibrary,
+        ] {
+            let q = reaction_q_value(u235, None, &[u236], library).unwrap();
+            assert!((q - 6.5455).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn reaction_q_value_charged() {
+        // N14(n,p)C14: Q = 0.6259 MeV
+        let n14 = Zai::new(7, 14, 0);
+        let c14 = Zai::new(6, 14, 0);
+        let h1 = Zai::new(1, 1, 0);
+        let q = reaction_q_value(n14, None, &[c14, h1], &EndfbAtomicMassLibrary).unwrap();
+        assert!((q - 0.6259).abs() < 0.01);
+        // H2(d,n)He3: Q = 3.2689 MeV
+        let h2 = Zai::new(1, 2, 0);
+        let he3 = Zai::new(2, 3, 0);
+        let q = reaction_q_value(h2, Some(h2), &[he3], &EndfbAtomicMassLibrary).unwrap();
+        assert!((q - 3.2689).abs() < 1e-3);
+    }
+
+    #[test]
+    fn reaction_q_value_invalid() {
+        let u235 = Zai::new(92, 235, 0);
+        // protons not conserved
+        let pu239 = Zai::new(94, 239, 0);
+        assert!(reaction_q_value(u235, None, &[pu239], &EndfbAtomicMassLibrary).is_none());
+        // more nucleons after reaction
+        let u237 = Zai::new(92, 237, 0);
+        assert!(reaction_q_value(u235, None, &[u237], &EndfbAtomicMassLibrary).is_none());
+        // missing atomic mass
+        let og400 = Zai::new(118, 400, 0);
+        let og401 = Zai::new(118, 401, 0);
+        assert!(reaction_q_value(og400, None, &[og401], &EndfbAtomicMassLibrary).is_none());
+    }
+
+    #[test]
+    fn mass_excess() {
+        let c12 = Zai::new(6, 12, 0);
+        let excess = EndfbAtomicMassLibrary.mass_excess(c12).unwrap();
+        assert!(excess.abs() < 1e-6);
+        let h1 = Zai::new(1, 1, 0);
+        let excess = EndfbAtomicMassLibrary.mass_excess(h1).unwrap();
+        assert!((excess - 7.289).abs() < 1e-3);
+    }
+
+    #[test]
+    fn init_atomic_masses_malformed() {
+        let source = "  1   1 0 H1      Hydrogen      H      1.007825
+  1   2 0 H2      Hydrogen      H    2.014102
+  1   X 0 H3      Hydrogen      H    3.016049
+";
+        let error = init_atomic_masses(source).unwrap_err();
+        assert_eq!(error.line(), 3);
+        let source = "  1   1 0 H1      Hydrogen      H      1.007825
+  1   2 0 H2      Hydrogen";
+        let error = init_atomic_masses(source).unwrap_err();
+        assert_eq!(error.line(), 2);
+    }
+
+    #[test]
+    fn custom() {
+        let source = "  1   1 0 H1      Hydrogen      H      1.007825
+ 92 235 0 U235    Uranium       U  235.043930
+ 95 242 1 Am242m1 Americium     Am 242.059549
+";
+        let library = CustomAtomicMassLibrary::from_reader(source.as_bytes()).unwrap();
+        assert_eq!(library.get(Zai::new(1, 1, 0)), Some(1.007825));
+        assert_eq!(library.get(Zai::new(92, 235, 0)), Some(235.043930));
+        assert_eq!(library.get(Zai::new(95, 242, 1)), Some(242.059549));
+        assert_eq!(library.get(Zai::new(95, 242, 0)), None);
+    }
+
+    #[test]
+    fn custom_malformed() {
+        let source = "  1   1 0 H1      Hydrogen      H      1.007825\n  1   2 0";
+        match CustomAtomicMassLibrary::from_reader(source.as_bytes()) {
+            Err(MassError::Parse(error)) => assert_eq!(error.line(), 2),
+            _ => panic!("expected parse error"),
+        }
+    }
+
+    #[test]
+    fn len() {
+        for (name, library) in [
+            ("ENDF/B", &EndfbAtomicMassLibrary as &dyn AtomicMassLibrary),
+            ("JEFF", &JeffAtomicMassLibrary),
+            ("JENDL", &JendlAtomicMassLibrary),
+        ] {
+            println!("{name}: {} nuclides", library.len());
+            assert!(!library.is_empty());
+            assert!(library.contains(Zai::new(92, 235, 0)));
+            assert!(!library.contains(Zai::new(118, 400, 0)));
+        }
+        let library = CustomAtomicMassLibrary::from_reader(&b""[..]).unwrap();
+        assert!(library.is_empty());
+    }
+
+    #[test]
+    fn iter() {
+        for library in [
+            &EndfbAtomicMassLibrary as &dyn AtomicMassLibrary,
+            &JeffAtomicMassLibrary,
+            &JendlAtomicMassLibrary,
+        ] {
+            assert!(library.iter().next().is_some());
+            assert_eq!(library.iter().count(), library.len());
+            for (zai, mass) in library.iter() {
+                assert_eq!(library.get(zai), Some(mass));
+            }
+        }
+    }
 }
@@ -0,0 +1,36 @@
+use std::{
+    cell::{Cell, UnsafeCell},
+    mem::MaybeUninit,
+    sync::Once,
+};
+
+// Lazy initialization.
+// Replace with std implementation after stabilization.
+pub(crate) struct Lazy<T, F = fn() -> T> {
+    once: Once,
+    init: Cell<Option<F>>,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T, F: FnOnce() -> T> Lazy<T, F> {
+    pub(crate) const fn new(init: F) -> Self {
+        Lazy {
+            once: Once::new(),
+            init: Cell::new(Some(init)),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    pub(crate) fn get(&self) -> &T {
+        self.once.call_once(|| {
+            let value = (self.init.take().unwrap())();
+            unsafe {
+                (*self.value.get()).write(value);
+            }
+        });
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
+
+unsafe impl<T: Sync + Send, F: Sync + Send> Sync for Lazy<T, F> {}
+unsafe impl<T: Send, F: Send> Send for Lazy<T, F> {}
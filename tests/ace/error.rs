@@ -0,0 +1,43 @@
+use std::error::Error;
+use std::io::{Error as IOError, ErrorKind};
+
+use nkl::data::ace::AceError;
+
+#[test]
+fn display() {
+    let errors = [
+        AceError::Data,
+        AceError::EndOfFile,
+        AceError::Format,
+        AceError::IO(IOError::from(ErrorKind::NotFound)),
+    ];
+    let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "invalid ACE data",
+            "reached end of ACE file",
+            "invalid ACE format",
+            "ACE I/O error",
+        ]
+    );
+}
+
+#[test]
+fn source() {
+    let error = AceError::from(IOError::from(ErrorKind::NotFound));
+    assert!(error.source().is_some());
+    assert!(AceError::Data.source().is_none());
+}
+
+#[test]
+fn non_exhaustive() {
+    // downstream matches require a wildcard arm
+    let recoverable = |error: &AceError| match error {
+        AceError::EndOfFile => true,
+        AceError::Data | AceError::Format => false,
+        _ => false,
+    };
+    assert!(recoverable(&AceError::EndOfFile));
+    assert!(!recoverable(&AceError::Data));
+}
@@ -1 +1,2 @@
+mod error;
 mod parse;
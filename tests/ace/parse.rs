@@ -1,7 +1,8 @@
 use std::error::Error;
 use std::io::Cursor;
 
-use nkl::data::ace::parse_ace_table;
+use nkl::core::Zai;
+use nkl::data::ace::{parse_ace_table, AceError};
 
 const IZAW: [(u32, f64); 16] = [
     (1, 1.0),
@@ -22,7 +23,7 @@ const IZAW: [(u32, f64); 16] = [
     (16, 16.0),
 ];
 
-const NXS: [usize; 16] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+const NXS: [usize; 16] = [4, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
 const JXS: [usize; 32] = [
     1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
     27, 28, 29, 30, 31, 32,
@@ -58,3 +59,162 @@ fn version2() -> Result<(), Box<dyn Error>> {
     assert_eq!(table.xss(), XSS);
     Ok(())
 }
+
+#[test]
+fn zai() -> Result<(), Box<dyn Error>> {
+    let ace = include_bytes!("data/zai.ace");
+    let cursor = Cursor::new(ace);
+    let table = parse_ace_table(cursor)?;
+    assert_eq!(table.id(), "92235.80c");
+    assert_eq!(table.xss_length(), 4);
+    assert_eq!(table.zaid_number(), 92235);
+    assert_eq!(table.energy_grid_length(), 1);
+    assert_eq!(table.zai(), Some(Zai::new(92, 235, 0)));
+    assert!((table.temperature_kelvin() - 293.6).abs() < 0.1);
+    Ok(())
+}
+
+#[test]
+fn xss_partial_line() -> Result<(), Box<dyn Error>> {
+    let ace = include_bytes!("data/xss.ace");
+    let cursor = Cursor::new(ace);
+    let table = parse_ace_table(cursor)?;
+    assert_eq!(table.xss_length(), 6);
+    assert_eq!(table.xss(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    Ok(())
+}
+
+#[test]
+fn xss_trailing_lines() -> Result<(), Box<dyn Error>> {
+    let ace = [include_str!("data/xss.ace"), "\n", "\n"].concat();
+    let cursor = Cursor::new(ace);
+    let table = parse_ace_table(cursor)?;
+    assert_eq!(table.xss().len(), table.nxs()[0]);
+    assert_eq!(table.xss(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    Ok(())
+}
+
+#[test]
+fn xss_truncated() {
+    let ace = include_str!("data/xss.ace");
+    let ace = &ace[..ace.trim_end().rfind('\n').unwrap()];
+    let cursor = Cursor::new(ace);
+    assert!(matches!(parse_ace_table(cursor), Err(AceError::Format)));
+}
+
+#[test]
+fn esz() -> Result<(), Box<dyn Error>> {
+    let ace = include_bytes!("data/esz.ace");
+    let cursor = Cursor::new(ace);
+    let table = parse_ace_table(cursor)?;
+    assert_eq!(table.energy_grid().len(), table.energy_grid_length());
+    assert_eq!(table.energy_grid(), [1.0e-11, 1.0, 20.0]);
+    assert_eq!(table.total_cross_section(), [30.0, 20.0, 1.0]);
+    Ok(())
+}
+
+#[test]
+fn esz_inconsistent() -> Result<(), Box<dyn Error>> {
+    let ace = include_bytes!("data/zai.ace");
+    let cursor = Cursor::new(ace);
+    let table = parse_ace_table(cursor)?;
+    assert!(table.energy_grid().is_empty());
+    assert!(table.total_cross_section().is_empty());
+    Ok(())
+}
+
+#[test]
+fn version1_truncated_header() {
+    let ace = include_str!("data/version1.ace").replacen(
+        "123.1234567 1.23456E-12 12/12/1234",
+        "123.12",
+        1,
+    );
+    let cursor = Cursor::new(ace);
+    assert!(matches!(parse_ace_table(cursor), Err(AceError::Format)));
+}
+
+#[test]
+fn version2_truncated_header() {
+    let ace = include_str!("data/version2.ace").replacen(
+        "1123123.123c                   source",
+        "1123",
+        1,
+    );
+    let cursor = Cursor::new(ace);
+    assert!(matches!(parse_ace_table(cursor), Err(AceError::Format)));
+    let ace = include_str!("data/version2.ace").replacen(" 12/12/1234    1", "", 1);
+    let cursor = Cursor::new(ace);
+    assert!(matches!(parse_ace_table(cursor), Err(AceError::Format)));
+}
+
+#[test]
+fn truncated_arrays() {
+    // IZAW (line 3), NXS (line 7) and JXS (line 9) lines truncated
+    for (index, length) in [(2, 20), (2, 71), (6, 18), (6, 71), (8, 40)] {
+        let ace: String = include_str!("data/version1.ace")
+            .lines()
+            .enumerate()
+            .map(|(i, line)| match i == index {
+                true => format!("{}\n", &line[..length]),
+                false => format!("{line}\n"),
+            })
+            .collect();
+        let cursor = Cursor::new(ace);
+        assert!(
+            matches!(parse_ace_table(cursor), Err(AceError::Format)),
+            "line {} truncated to {length} columns",
+            index + 1
+        );
+    }
+}
+
+#[test]
+fn xss_length_zero() {
+    let ace =
+        include_str!("data/version1.ace").replacen("        4        2", "        0        2", 1);
+    let cursor = Cursor::new(ace);
+    assert!(matches!(parse_ace_table(cursor), Err(AceError::Data)));
+}
+
+#[test]
+fn zaid_malformed() {
+    let ace = include_str!("data/version1.ace").replacen("12345.12c", "12345    ", 1);
+    let cursor = Cursor::new(ace);
+    assert!(matches!(parse_ace_table(cursor), Err(AceError::Data)));
+}
+
+#[test]
+fn named_arrays() -> Result<(), Box<dyn Error>> {
+    let ace = include_bytes!("data/version1.ace");
+    let cursor = Cursor::new(ace);
+    let table = parse_ace_table(cursor)?;
+    let nxs = table.named_nxs();
+    assert_eq!(nxs.as_slice(), table.nxs());
+    assert_eq!(nxs.xss_length(), table.nxs()[0]);
+    assert_eq!(nxs.za(), table.nxs()[1]);
+    assert_eq!(nxs.energy_grid_length(), table.nxs()[2]);
+    assert_eq!(nxs.mass_number(), table.nxs()[10]);
+    let jxs = table.named_jxs();
+    assert_eq!(jxs.as_slice(), table.jxs());
+    assert_eq!(jxs.energy_table(), table.jxs()[0]);
+    assert_eq!(jxs.fission(), table.jxs()[20]);
+    assert_eq!(jxs.end(), table.jxs()[21]);
+    assert_eq!(jxs.particle_production_locators(), table.jxs()[31]);
+    Ok(())
+}
+
+#[test]
+fn table_predicates() -> Result<(), Box<dyn Error>> {
+    let ace = include_bytes!("data/version1.ace");
+    let table = parse_ace_table(Cursor::new(ace))?;
+    assert!(table.is_continuous_energy());
+    assert!(!table.is_thermal_scattering());
+    assert!(!table.is_photoatomic());
+    assert!(!table.is_dosimetry());
+    let ace = include_str!("data/version1.ace").replacen("12345.12c", "lwtr.20t ", 1);
+    let table = parse_ace_table(Cursor::new(ace))?;
+    assert!(table.is_thermal_scattering());
+    assert!(!table.is_continuous_energy());
+    Ok(())
+}
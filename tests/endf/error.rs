@@ -0,0 +1,60 @@
+use std::error::Error;
+use std::io::{Error as IOError, ErrorKind};
+
+use nkl::data::endf::EndfError;
+
+#[test]
+fn display() {
+    let errors = [
+        EndfError::At {
+            line: 12,
+            source: Box::new(EndfError::Data),
+        },
+        EndfError::Data,
+        EndfError::Encoding,
+        EndfError::EndOfFile,
+        EndfError::Format,
+        EndfError::IO(IOError::from(ErrorKind::NotFound)),
+    ];
+    let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "ENDF error at line 12: invalid ENDF data",
+            "invalid ENDF data",
+            "ENDF encoding error",
+            "reached end of ENDF file",
+            "invalid ENDF format",
+            "ENDF I/O error",
+        ]
+    );
+}
+
+#[test]
+fn source() {
+    let error = EndfError::At {
+        line: 1,
+        source: Box::new(EndfError::Format),
+    };
+    assert!(matches!(
+        error
+            .source()
+            .and_then(|source| source.downcast_ref::<EndfError>()),
+        Some(EndfError::Format)
+    ));
+    let error = EndfError::from(IOError::from(ErrorKind::NotFound));
+    assert!(error.source().is_some());
+    assert!(EndfError::Data.source().is_none());
+}
+
+#[test]
+fn non_exhaustive() {
+    // downstream matches require a wildcard arm
+    let recoverable = |error: &EndfError| match error {
+        EndfError::EndOfFile => true,
+        EndfError::At { .. } | EndfError::Data | EndfError::Format => false,
+        _ => false,
+    };
+    assert!(recoverable(&EndfError::EndOfFile));
+    assert!(!recoverable(&EndfError::Encoding));
+}
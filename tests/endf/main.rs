@@ -1 +1,5 @@
+mod error;
+mod parse;
 mod read;
+mod tape;
+mod write;
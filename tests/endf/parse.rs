@@ -0,0 +1,81 @@
+use std::{error::Error, io::Cursor};
+
+use nkl::data::endf::{
+    parse_head, parse_list, parse_tab1, parse_tab2, record_kind, EndfError, EndfReader, RecordKind,
+};
+
+// Split fixture into lines (line endings included).
+fn lines(endf: &[u8]) -> Vec<&[u8]> {
+    endf.split_inclusive(|&b| b == b'\n').collect()
+}
+
+#[test]
+fn head() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/head.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert_eq!(parse_head(endf)?, reader.read_head()?);
+    Ok(())
+}
+
+#[test]
+fn list() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/list.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert_eq!(parse_list(&lines(endf))?, reader.read_list()?);
+    Ok(())
+}
+
+#[test]
+fn tab1() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/tab1.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert_eq!(parse_tab1(&lines(endf))?, reader.read_tab1()?);
+    Ok(())
+}
+
+#[test]
+fn tab2() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/tab2.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert_eq!(parse_tab2(&lines(endf))?, reader.read_tab2()?);
+    Ok(())
+}
+
+#[test]
+fn missing_lines() {
+    let endf = include_bytes!("data/tab1.endf");
+    let lines = lines(endf);
+    assert!(matches!(
+        parse_tab1(&lines[..lines.len() - 1]),
+        Err(EndfError::EndOfFile)
+    ));
+    assert!(matches!(
+        parse_list::<&[u8]>(&[]),
+        Err(EndfError::EndOfFile)
+    ));
+}
+
+#[test]
+fn kind() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/end.endf");
+    let kinds = lines(endf)
+        .into_iter()
+        .map(record_kind)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(
+        kinds,
+        [
+            RecordKind::Send,
+            RecordKind::Fend,
+            RecordKind::Mend,
+            RecordKind::Tend
+        ]
+    );
+    let endf = include_bytes!("data/list.endf");
+    for line in lines(endf) {
+        assert_eq!(record_kind(line)?, RecordKind::Data);
+    }
+    let line = " 0.00000000 0.00000000          0          0          0          0   0 3  0    0";
+    assert!(matches!(record_kind(line), Err(EndfError::Data)));
+    Ok(())
+}
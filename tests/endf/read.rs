@@ -1,6 +1,9 @@
 use std::{error::Error, io::Cursor};
 
-use nkl::data::endf::{Cont, EndfReader, Intg, List, Tab1, Tab2, Text};
+use nkl::data::endf::{
+    is_fend, is_mend, is_send, is_tend, Cont, DirEntry, EndfError, EndfReader, Head, Intg, List,
+    Tab1, Tab2, Text,
+};
 
 #[test]
 fn line() -> Result<(), Box<dyn Error>> {
@@ -30,6 +33,20 @@ fn line() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn lines_iter() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/default.endf");
+    let cursor = Cursor::new(endf);
+    let reader = EndfReader::new(cursor);
+    let lines = reader.lines_iter().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(lines.len(), 5);
+    assert_eq!(
+        lines[4],
+        b" 7.00000000 8.00000000         13         14         15         16   1 1451    4\n"
+    );
+    Ok(())
+}
+
 #[test]
 fn cont() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/cont.endf");
@@ -60,6 +77,35 @@ fn dir() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn dir_entry() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/dir.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    let entry = reader.read_dir()?;
+    assert_eq!(
+        entry,
+        DirEntry {
+            mf: 1,
+            mt: 2,
+            lines: 3,
+            modification: 4
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn dir_entry_negative() {
+    let endf =
+        b"                                1         -2          3          41234 1451    1\n";
+    let mut reader = EndfReader::new(Cursor::new(&endf[..]));
+    assert!(matches!(
+        reader.read_dir(),
+        Err(EndfError::At { line: 1, source }) if matches!(*source, EndfError::Data)
+    ));
+}
+
 #[test]
 fn end() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/end.endf");
@@ -77,6 +123,31 @@ fn end() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn control() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/end.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    // SEND
+    let (mat, mf, mt, ns) = reader.read_control()?;
+    assert_eq!((mat, mf, mt, ns), (1234, 12, 0, Some(0)));
+    assert!(is_send(mat, mf, mt));
+    assert!(!is_fend(mat, mf, mt) && !is_mend(mat, mf, mt) && !is_tend(mat, mf, mt));
+    // FEND
+    let (mat, mf, mt, _) = reader.read_control()?;
+    assert!(is_fend(mat, mf, mt));
+    assert!(!is_send(mat, mf, mt) && !is_mend(mat, mf, mt) && !is_tend(mat, mf, mt));
+    // MEND
+    let (mat, mf, mt, _) = reader.read_control()?;
+    assert!(is_mend(mat, mf, mt));
+    assert!(!is_send(mat, mf, mt) && !is_fend(mat, mf, mt) && !is_tend(mat, mf, mt));
+    // TEND
+    let (mat, mf, mt, _) = reader.read_control()?;
+    assert!(is_tend(mat, mf, mt));
+    assert!(!is_send(mat, mf, mt) && !is_fend(mat, mf, mt) && !is_mend(mat, mf, mt));
+    Ok(())
+}
+
 #[test]
 fn head() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/dir.endf");
@@ -92,6 +163,21 @@ fn head() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn head_record() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/head.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    let Head(za, awr, l1, l2, n1, n2) = reader.read_head()?;
+    assert_eq!(za, 1.);
+    assert_eq!(awr, 2.);
+    assert_eq!(l1, 1);
+    assert_eq!(l2, 2);
+    assert_eq!(n1, 3);
+    assert_eq!(n2, 4);
+    Ok(())
+}
+
 #[test]
 fn intg() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/intg.endf");
@@ -114,6 +200,45 @@ fn intg() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn intg_entries() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/intg.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    let intg = reader.read_intg(2)?;
+    assert_eq!((intg.row(), intg.col()), (12345, 12345));
+    assert_eq!(intg.values(), [123; 18]);
+    let entries: Vec<_> = intg.entries().collect();
+    assert_eq!(entries.len(), 18);
+    for (k, &(i, j, value)) in entries.iter().enumerate() {
+        assert_eq!((i, j, value), (12345, 12345 + k, 123));
+    }
+    Ok(())
+}
+
+#[test]
+fn intg_negative() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/intg_negative.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    for ndigit in 2..=6 {
+        let Intg(ii, jj, kij) = reader.read_intg(ndigit)?;
+        assert_eq!(ii, 12);
+        assert_eq!(jj, 3);
+        let len = [18, 13, 11, 9, 8][ndigit - 2];
+        let min = -(10i64.pow(ndigit as u32) - 1);
+        let expected: Vec<i64> = (1..=len as i64)
+            .map(|i| match i % 3 {
+                1 => min,
+                2 => -i,
+                _ => i,
+            })
+            .collect();
+        assert_eq!(kij, expected);
+    }
+    Ok(())
+}
+
 #[test]
 fn list() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/list.endf");
@@ -131,6 +256,36 @@ fn list() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn line_number() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/default.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    assert_eq!(reader.line_number(), 0);
+    reader.read_line()?;
+    assert_eq!(reader.line_number(), 1);
+    reader.read_cont()?;
+    reader.read_cont()?;
+    assert_eq!(reader.line_number(), 3);
+    let endf = include_bytes!("data/tab1.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    reader.read_tab1()?;
+    assert_eq!(reader.line_number(), 4);
+    Ok(())
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn list_gzip() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/list.endf.gz");
+    let mut reader = EndfReader::from_gzip(&endf[..]);
+    let List(_, _, _, _, npl, _, b) = reader.read_list()?;
+    assert_eq!(npl, 3);
+    assert_eq!(b, vec![1., 2., 3.]);
+    Ok(())
+}
+
 #[test]
 fn tab1() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/tab1.endf");
@@ -167,6 +322,57 @@ fn tab2() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn tab2_tab1() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/tab2_tab1.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    let (tab2, tab1s) = reader.read_tab2_tab1()?;
+    assert_eq!(tab2.nz(), 2);
+    assert_eq!(tab2.regions(), [(2, 2)]);
+    assert_eq!(tab1s.len(), 2);
+    assert_eq!(tab1s[0].c2(), 1.0e6);
+    assert_eq!(tab1s[0].points(), [(1., 2.), (3., 4.)]);
+    assert_eq!(tab1s[1].c2(), 2.0e6);
+    assert_eq!(tab1s[1].regions(), [(3, 2)]);
+    assert_eq!(tab1s[1].points(), [(1., 3.), (2., 4.), (3., 5.)]);
+    assert_eq!(reader.line_number(), 8);
+    Ok(())
+}
+
+#[test]
+fn tab2_tab1_truncated() {
+    let endf = include_str!("data/tab2_tab1.endf");
+    let endf: String = endf
+        .lines()
+        .take(5)
+        .map(|line| format!("{line}\n"))
+        .collect();
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert!(matches!(reader.read_tab2_tab1(), Err(EndfError::EndOfFile)));
+}
+
+#[test]
+fn tab1_into() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/tab2_tab1.endf");
+    let mut expected = EndfReader::new(Cursor::new(endf));
+    expected.read_tab2()?;
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    reader.read_tab2()?;
+    let (mut int, mut tab) = (Vec::new(), Vec::new());
+    for _ in 0..2 {
+        let Tab1(c1, c2, l1, l2, _, _, expected_int, expected_tab) = expected.read_tab1()?;
+        assert_eq!(reader.read_tab1_into(&mut int, &mut tab)?, (c1, c2, l1, l2));
+        assert_eq!(int, expected_int);
+        assert_eq!(tab, expected_tab);
+    }
+    assert!(matches!(
+        reader.read_tab1_into(&mut int, &mut tab),
+        Err(EndfError::EndOfFile)
+    ));
+    Ok(())
+}
+
 #[test]
 fn text() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/text.endf");
@@ -180,6 +386,72 @@ fn text() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn text_crlf() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/text_crlf.endf");
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    for _ in 0..2 {
+        let Text(hl) = reader.read_text()?;
+        assert_eq!(
+            hl,
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZ abcdefghijklmnopqrstuvwxyz 0123456789  "
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn text_short() {
+    let endf = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ abcdefghijklmnopqrstuvwxyz 0123456789 \r\nABC\n";
+    let cursor = Cursor::new(endf);
+    let mut reader = EndfReader::new(cursor);
+    assert!(matches!(
+        reader.read_text(),
+        Err(EndfError::At { line: 1, source }) if matches!(*source, EndfError::Format)
+    ));
+    assert!(matches!(
+        reader.read_text(),
+        Err(EndfError::At { line: 2, source }) if matches!(*source, EndfError::Format)
+    ));
+}
+
+#[test]
+fn short_lines() {
+    let endf = b"          1          2\n";
+    for read in [
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_cont().map(|_| ()),
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_head().map(|_| ()),
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_intg(2).map(|_| ()),
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_list().map(|_| ()),
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_tab1().map(|_| ()),
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_tab2().map(|_| ()),
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_text().map(|_| ()),
+        |reader: &mut EndfReader<Cursor<&[u8]>>| reader.read_control().map(|_| ()),
+    ] {
+        let mut reader = EndfReader::new(Cursor::new(&endf[..]));
+        assert!(matches!(
+            read(&mut reader),
+            Err(EndfError::At { line: 1, source }) if matches!(*source, EndfError::Format)
+        ));
+    }
+}
+
+#[test]
+fn error_line() {
+    let endf = b" 1.00000000 2.00000000          1          2          3          4   1 1451    1\n 3.00000000 4.00000000          5          X          7          8   1 1451    2\n";
+    let cursor = Cursor::new(&endf[..]);
+    let mut reader = EndfReader::new(cursor);
+    assert!(reader.read_cont().is_ok());
+    let error = reader.read_cont().unwrap_err();
+    assert!(matches!(
+        &error,
+        EndfError::At { line: 2, source } if matches!(**source, EndfError::Data)
+    ));
+    assert_eq!(error.to_string(), "ENDF error at line 2: invalid ENDF data");
+    assert!(matches!(reader.read_cont(), Err(EndfError::EndOfFile)));
+}
+
 #[test]
 fn tpid() -> Result<(), Box<dyn Error>> {
     let endf = include_bytes!("data/tpid.endf");
@@ -192,3 +464,123 @@ fn tpid() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn sequence_gap() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/sequence.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert_eq!(reader.read_list()?.values().len(), 8);
+    let mut reader = EndfReader::new(Cursor::new(endf)).with_validation();
+    assert!(matches!(
+        reader.read_list(),
+        Err(EndfError::At { line: 3, source }) if matches!(*source, EndfError::Data)
+    ));
+    Ok(())
+}
+
+#[test]
+fn sequence_valid() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/tape.endf");
+    let reader = EndfReader::new(Cursor::new(endf)).with_validation();
+    assert_eq!(
+        reader.lines_iter().collect::<Result<Vec<_>, _>>()?.len(),
+        17
+    );
+    Ok(())
+}
+
+#[test]
+fn skip_section() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/tape.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf)).with_validation();
+    // TPID
+    assert_eq!(reader.read_control()?, (1, 0, 0, Some(0)));
+    // MF=1/MT=451
+    assert_eq!(reader.skip_section()?, 3);
+    // FEND
+    assert_eq!(reader.read_control()?, (125, 0, 0, Some(0)));
+    // MF=3/MT=1
+    assert_eq!(reader.skip_section()?, 3);
+    assert_eq!(reader.line_number(), 8);
+    assert_eq!(reader.read_control()?, (125, 3, 2, Some(1)));
+    // end of MF=3/MT=2
+    assert_eq!(reader.skip_section()?, 1);
+    assert_eq!(reader.line_number(), 10);
+    Ok(())
+}
+
+#[test]
+fn skip_section_truncated() {
+    let endf = include_bytes!("data/tab1.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert!(matches!(reader.skip_section(), Err(EndfError::EndOfFile)));
+}
+
+#[test]
+fn mt451_description() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/mt451.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf)).with_validation();
+    reader.read_head()?;
+    reader.read_cont()?;
+    reader.read_cont()?;
+    let Cont(_, _, _, _, nwd, nxc) = reader.read_cont()?;
+    assert_eq!((nwd, nxc), (3, 2));
+    assert_eq!(
+        reader.read_mt451_description(nwd as usize)?,
+        " 92-U -235 LANL       EVAL-JUN18 Sample Author\n\n ----SAMPLE EVALUATION      MATERIAL 9228"
+    );
+    assert_eq!(reader.line_number(), 7);
+    assert_eq!(
+        reader.read_dir()?,
+        DirEntry {
+            mf: 1,
+            mt: 451,
+            lines: 9,
+            modification: 0
+        }
+    );
+    assert_eq!(
+        reader.read_dir()?,
+        DirEntry {
+            mf: 3,
+            mt: 1,
+            lines: 3,
+            modification: 0
+        }
+    );
+    assert_eq!(reader.read_control()?, (9228, 1, 0, Some(99999)));
+    Ok(())
+}
+
+#[test]
+fn mt451_description_truncated() {
+    let endf = include_str!("data/mt451.endf");
+    let endf: String = endf
+        .lines()
+        .take(6)
+        .map(|line| format!("{line}\n"))
+        .collect();
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert!(matches!(
+        reader.read_mt451_description(8),
+        Err(EndfError::EndOfFile)
+    ));
+    // SEND record reached before NWD records
+    let endf = include_bytes!("data/mt451.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert!(matches!(
+        reader.read_mt451_description(11),
+        Err(EndfError::At { line: 10, .. })
+    ));
+}
+
+#[test]
+fn text_encoding() {
+    let mut endf = include_bytes!("data/text.endf").to_vec();
+    endf[3] = 0xE9;
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    assert!(matches!(
+        reader.read_text(),
+        Err(EndfError::At { line: 1, source }) if matches!(*source, EndfError::Encoding)
+    ));
+}
@@ -0,0 +1,65 @@
+use std::{error::Error, io::Cursor};
+
+use nkl::data::endf::{EndfError, Tape};
+
+#[test]
+fn index() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/tape.endf");
+    let tape = Tape::from_reader(Cursor::new(endf))?;
+    assert_eq!(tape.lines().len(), 17);
+    let sections = tape.sections().collect::<Vec<_>>();
+    assert_eq!(
+        sections,
+        [(125, 1, 451), (125, 3, 1), (125, 3, 2), (128, 3, 1)]
+    );
+    let section = tape.section(125, 1, 451).unwrap();
+    assert_eq!(section.len(), 2);
+    assert_eq!(
+        section[1],
+        b" 1.00000000 2.00000000          1          2          3          4 125 1451    2"
+    );
+    let section = tape.section(125, 3, 1).unwrap();
+    assert_eq!(section.len(), 2);
+    assert!(section[0].ends_with(b" 125 3  1    1"));
+    assert!(section[1].ends_with(b" 125 3  1    2"));
+    assert_eq!(tape.section(125, 3, 2).unwrap().len(), 1);
+    assert_eq!(tape.section(128, 3, 1).unwrap().len(), 1);
+    assert!(tape.section(128, 3, 2).is_none());
+    assert!(tape.section(125, 0, 0).is_none());
+    Ok(())
+}
+
+#[test]
+fn repeated_section() -> Result<(), Box<dyn Error>> {
+    let endf = concat!(
+        "TAPE                                                                 1 0  0    0\n",
+        " 1.00000000 2.00000000          0          0          0          0 125 3  1    1\n",
+        " 1.00000000 2.00000000          0          0          0          0 125 3  2    1\n",
+        " 3.00000000 4.00000000          0          0          0          0 125 3  1    1\n",
+        " 5.00000000 6.00000000          0          0          0          0 125 3  1    2\n",
+    );
+    let tape = Tape::from_reader(Cursor::new(endf))?;
+    let sections = tape.sections().collect::<Vec<_>>();
+    assert_eq!(sections, [(125, 3, 1), (125, 3, 2), (125, 3, 1)]);
+    let occurrences = tape.section_occurrences(125, 3, 1).collect::<Vec<_>>();
+    assert_eq!(occurrences.len(), 2);
+    assert_eq!(occurrences[0].len(), 1);
+    assert_eq!(occurrences[1].len(), 2);
+    assert!(occurrences[1][0].starts_with(b" 3.00000000"));
+    assert_eq!(tape.section(125, 3, 1), Some(occurrences[0]));
+    assert_eq!(tape.section_occurrences(125, 3, 3).count(), 0);
+    Ok(())
+}
+
+#[test]
+fn malformed_control() {
+    let endf = concat!(
+        "TAPE                                                                 1 0  0    0\n",
+        " 1.00000000 2.00000000          0          0          0          0 125 3  1    1\n",
+        " 1.00000000 2.00000000          0          0          0          0 125 3  X    2\n",
+    );
+    assert!(matches!(
+        Tape::from_reader(Cursor::new(endf)),
+        Err(EndfError::At { line: 3, .. })
+    ));
+}
@@ -0,0 +1,80 @@
+use std::{error::Error, io::Cursor};
+
+use nkl::data::endf::{Cont, EndfError, EndfReader, EndfWriter, Text};
+
+#[test]
+fn round_trip() -> Result<(), Box<dyn Error>> {
+    let endf = include_bytes!("data/write.endf");
+    let mut reader = EndfReader::new(Cursor::new(endf));
+    let mut writer = EndfWriter::new(Vec::new());
+    writer.set_section(9228, 3, 1)?;
+    writer.write_text(&reader.read_text()?)?;
+    writer.write_cont(&reader.read_cont()?)?;
+    writer.write_list(&reader.read_list()?)?;
+    writer.write_tab1(&reader.read_tab1()?)?;
+    writer.write_tab2(&reader.read_tab2()?)?;
+    assert_eq!(writer.into_inner(), endf);
+    Ok(())
+}
+
+#[test]
+fn sequence() -> Result<(), Box<dyn Error>> {
+    let mut writer = EndfWriter::new(Vec::new());
+    writer.set_section(125, 1, 451)?;
+    writer.write_cont(&Cont(1.0, -1.5, 1, -2, 3, 4))?;
+    writer.write_cont(&Cont(0.0, 0.0, 0, 0, 0, 0))?;
+    writer.set_section(125, 3, 1)?;
+    writer.write_cont(&Cont(1e-12, 123456789.0, 0, 0, 0, 0))?;
+    assert_eq!(
+        String::from_utf8(writer.into_inner())?,
+        concat!(
+            " 1.00000000-1.50000000          1         -2          3          4 125 1451    1\n",
+            " 0.000000+0 0.000000+0          0          0          0          0 125 1451    2\n",
+            " 1.00000-12  123456789          0          0          0          0 125 3  1    1\n",
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    let mut writer = EndfWriter::new(Vec::new());
+    assert!(matches!(
+        writer.write_cont(&Cont(f64::NAN, 0.0, 0, 0, 0, 0)),
+        Err(EndfError::Data)
+    ));
+    assert!(matches!(
+        writer.write_cont(&Cont(0.0, 0.0, i64::MAX, 0, 0, 0)),
+        Err(EndfError::Data)
+    ));
+    assert!(matches!(
+        writer.write_cont(&Cont(0.0, 0.0, 10_000_000_000, 0, 0, 0)),
+        Err(EndfError::Data)
+    ));
+    assert!(matches!(
+        writer.write_text(&Text("A".repeat(67))),
+        Err(EndfError::Data)
+    ));
+    assert!(matches!(
+        writer.write_text(&Text("é".into())),
+        Err(EndfError::Encoding)
+    ));
+    assert!(writer.into_inner().is_empty());
+}
+
+#[test]
+fn invalid_section() -> Result<(), Box<dyn Error>> {
+    let mut writer = EndfWriter::new(Vec::new());
+    writer.set_section(-1, 0, 0)?;
+    writer.set_section(9999, 99, 999)?;
+    for (mat, mf, mt) in [(12345, 3, 1), (-2, 3, 1), (125, 100, 1), (125, 3, 1000)] {
+        assert!(matches!(
+            writer.set_section(mat, mf, mt),
+            Err(EndfError::Data)
+        ));
+    }
+    // control numbers are left unchanged
+    writer.write_cont(&Cont(0.0, 0.0, 0, 0, 0, 0))?;
+    assert!(writer.into_inner().ends_with(b"999999999    1\n"));
+    Ok(())
+}